@@ -3,7 +3,7 @@
 use std::iter;
 
 use crate::{
-    DigitGrid,
+    DigitGrid, House,
     containers::{Array9, BitSet9, BitSet81},
     digit::Digit,
     index::{CellIndexSemantics, DigitSemantics, Index9, Index9Semantics, PositionSemantics},
@@ -180,6 +180,33 @@ impl DigitPositions {
         }
         mask
     }
+
+    /// Returns a bitmask of positions in the specified house.
+    ///
+    /// Dispatches to [`row_mask`](Self::row_mask), [`col_mask`](Self::col_mask),
+    /// or [`box_mask`](Self::box_mask), so the returned mask contains the cell
+    /// indices as defined by [`House`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_core::{DigitPositions, House, Position};
+    ///
+    /// let mut positions = DigitPositions::new();
+    /// positions.insert(Position::new(2, 0));
+    /// positions.insert(Position::new(5, 0));
+    ///
+    /// assert_eq!(positions.house_mask(House::Row { y: 0 }), positions.row_mask(0));
+    /// assert_eq!(positions.house_mask(House::Column { x: 2 }).len(), 1);
+    /// ```
+    #[must_use]
+    pub fn house_mask(&self, house: House) -> HouseMask {
+        match house {
+            House::Row { y } => self.row_mask(y),
+            House::Column { x } => self.col_mask(x),
+            House::Box { index } => self.box_mask(index),
+        }
+    }
 }
 
 /// A set of cell indices (0-8) within a house (row, column, or box).
@@ -285,13 +312,16 @@ impl Default for CandidateGrid {
 /// - [`NoCandidates`]: A cell has no remaining candidates, making it impossible to place a digit
 /// - [`DuplicatedDecidedDigits`]: Multiple cells in the same row, column, or box have the same decided digit
 ///
+/// Each variant records where the inconsistency was found, so that it can be
+/// pointed out to the player or traced back to the step that caused it.
+///
 /// [`NoCandidates`]: ConsistencyError::NoCandidates
 /// [`DuplicatedDecidedDigits`]: ConsistencyError::DuplicatedDecidedDigits
 ///
 /// # Examples
 ///
 /// ```
-/// use numelace_core::{CandidateGrid, Digit, Position};
+/// use numelace_core::{CandidateGrid, ConsistencyError, Digit, Position};
 ///
 /// let mut grid = CandidateGrid::new();
 ///
@@ -302,22 +332,35 @@ impl Default for CandidateGrid {
 /// }
 ///
 /// // check_consistency will detect this as NoCandidates error
-/// assert!(grid.check_consistency().is_err());
+/// assert_eq!(
+///     grid.check_consistency(),
+///     Err(ConsistencyError::NoCandidates { pos })
+/// );
 /// ```
-#[derive(Debug, Clone, Copy, derive_more::Display, derive_more::Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, derive_more::Error)]
 pub enum ConsistencyError {
     /// A cell has no remaining candidates.
     ///
     /// This occurs when candidate removal results in a cell with no possible digits,
     /// making the puzzle unsolvable.
-    #[display("candidate grid has cells with no candidates")]
-    NoCandidates,
+    #[display("cell {pos} has no candidates")]
+    NoCandidates {
+        /// The first cell, in index order, without candidates.
+        pos: Position,
+    },
     /// Multiple cells in the same constraint region have the same decided digit.
     ///
     /// This occurs when the same digit appears more than once in a row, column, or box,
     /// violating Sudoku rules.
-    #[display("candidate grid has duplicated decided digits")]
-    DuplicatedDecidedDigits,
+    #[display("digit {digit} is decided at both {} and {} in {house}", positions[0], positions[1])]
+    DuplicatedDecidedDigits {
+        /// The duplicated digit.
+        digit: Digit,
+        /// The house in which the digit is duplicated.
+        house: House,
+        /// The first two cells of the house, in index order, where the digit is decided.
+        positions: [Position; 2],
+    },
 }
 
 impl CandidateGrid {
@@ -491,6 +534,14 @@ impl CandidateGrid {
         self.digit_positions[digit].box_mask(box_index)
     }
 
+    /// Returns a bitmask of candidate positions in the specified house for the digit.
+    ///
+    /// If the returned mask has only one bit set, a Hidden Single is detected.
+    #[must_use]
+    pub fn house_mask(&self, house: House, digit: Digit) -> HouseMask {
+        self.digit_positions[digit].house_mask(house)
+    }
+
     /// Checks if the grid is in a consistent state.
     ///
     /// Returns `Ok(())` if the grid is consistent, or `Err(ConsistencyError)` if:
@@ -526,12 +577,10 @@ impl CandidateGrid {
     /// [`is_solved`]: CandidateGrid::is_solved
     pub fn check_consistency(&self) -> Result<(), ConsistencyError> {
         let [empty_cells, decided_cells] = self.classify_cells();
-        if !empty_cells.is_empty() {
-            return Err(ConsistencyError::NoCandidates);
-        }
-        if !self.placed_digits_are_unique(decided_cells) {
-            return Err(ConsistencyError::DuplicatedDecidedDigits);
+        if let Some(pos) = empty_cells.first() {
+            return Err(ConsistencyError::NoCandidates { pos });
         }
+        self.check_placed_digits_are_unique(decided_cells)?;
         Ok(())
     }
 
@@ -557,12 +606,10 @@ impl CandidateGrid {
     /// ```
     pub fn is_solved(&self) -> Result<bool, ConsistencyError> {
         let [empty_cells, decided_cells] = self.classify_cells();
-        if !empty_cells.is_empty() {
-            return Err(ConsistencyError::NoCandidates);
-        }
-        if !self.placed_digits_are_unique(decided_cells) {
-            return Err(ConsistencyError::DuplicatedDecidedDigits);
+        if let Some(pos) = empty_cells.first() {
+            return Err(ConsistencyError::NoCandidates { pos });
         }
+        self.check_placed_digits_are_unique(decided_cells)?;
         Ok(decided_cells.len() == 81)
     }
 
@@ -679,34 +726,38 @@ impl CandidateGrid {
 
     /// Checks that definite digits have no duplicates in rows, columns, or boxes.
     ///
-    /// For each position in `decided_cells`, verifies that its digit appears
-    /// exactly once in its respective row, column, and 3×3 box.
+    /// For each digit, verifies that it is decided at most once in every row,
+    /// column, and 3×3 box.
     ///
     /// # Arguments
     ///
     /// * `decided_cells` - Positions where exactly one candidate remains
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// `true` if all definite digits satisfy sudoku uniqueness constraints,
-    /// `false` if any digit appears multiple times in the same row, column, or box.
-    fn placed_digits_are_unique(&self, decided_cells: DigitPositions) -> bool {
+    /// Returns [`ConsistencyError::DuplicatedDecidedDigits`] for the first digit,
+    /// and the first house of that digit, with a duplicate.
+    fn check_placed_digits_are_unique(
+        &self,
+        decided_cells: DigitPositions,
+    ) -> Result<(), ConsistencyError> {
         for digit in Digit::ALL {
-            let digit_cells = &self.digit_positions[digit];
-            let decided_digit_cells = *digit_cells & decided_cells;
-            for pos in decided_digit_cells {
-                if decided_digit_cells.row_mask(pos.y()).len() != 1 {
-                    return false;
-                }
-                if decided_digit_cells.col_mask(pos.x()).len() != 1 {
-                    return false;
-                }
-                if decided_digit_cells.box_mask(pos.box_index()).len() != 1 {
-                    return false;
+            let decided_digit_cells = self.digit_positions[digit] & decided_cells;
+            if decided_digit_cells.len() < 2 {
+                continue;
+            }
+            for house in House::ALL {
+                let mut cells = (decided_digit_cells & house.positions()).iter();
+                if let (Some(first), Some(second)) = (cells.next(), cells.next()) {
+                    return Err(ConsistencyError::DuplicatedDecidedDigits {
+                        digit,
+                        house,
+                        positions: [first, second],
+                    });
                 }
             }
         }
-        true
+        Ok(())
     }
 }
 
@@ -886,13 +937,44 @@ mod tests {
         for digit in Digit::ALL {
             grid.remove_candidate(pos, digit);
         }
-        assert!(grid.check_consistency().is_err());
+        assert_eq!(
+            grid.check_consistency(),
+            Err(ConsistencyError::NoCandidates { pos })
+        );
 
         // Detects violation: duplicate digits in same house
         let mut grid = CandidateGrid::new();
         grid.place(Position::new(0, 0), D5);
         grid.place(Position::new(0, 1), D5);
-        assert!(grid.check_consistency().is_err());
+        assert_eq!(
+            grid.check_consistency(),
+            Err(ConsistencyError::DuplicatedDecidedDigits {
+                digit: D5,
+                house: House::Column { x: 0 },
+                positions: [Position::new(0, 0), Position::new(0, 1)],
+            })
+        );
+    }
+
+    #[test]
+    fn test_consistency_error_display() {
+        let mut grid = CandidateGrid::new();
+        grid.place(Position::new(3, 4), D7);
+        grid.place(Position::new(5, 3), D7);
+        let err = grid.check_consistency().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "digit 7 is decided at both r4c6 and r5c4 in box 5"
+        );
+
+        let mut grid = CandidateGrid::new();
+        for digit in Digit::ALL {
+            grid.remove_candidate(Position::new(8, 0), digit);
+        }
+        assert_eq!(
+            grid.is_solved().unwrap_err().to_string(),
+            "cell r1c9 has no candidates"
+        );
     }
 
     #[test]
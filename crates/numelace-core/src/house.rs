@@ -0,0 +1,175 @@
+//! House (row, column, or box) types.
+
+use std::fmt::{self, Display};
+
+use crate::{DigitPositions, Position};
+
+/// A house: a row, a column, or a 3×3 box.
+///
+/// Each house contains exactly nine cells, and each digit must appear exactly
+/// once in every house. Cells within a house are addressed by a cell index (0-8):
+///
+/// - **Row**: the column (x coordinate) of the cell
+/// - **Column**: the row (y coordinate) of the cell
+/// - **Box**: the relative position within the box (see [`Position::box_cell_index`])
+///
+/// This matches the indices returned by [`DigitPositions::row_mask`],
+/// [`DigitPositions::col_mask`], and [`DigitPositions::box_mask`].
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::{House, Position};
+///
+/// let house = House::Box { index: 4 };
+/// assert_eq!(house.position(0), Position::new(3, 3));
+/// assert!(house.positions().contains(Position::new(5, 5)));
+/// assert_eq!(house.to_string(), "box 5");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum House {
+    /// The row with the given y coordinate (0-8).
+    Row {
+        /// The y coordinate of the row.
+        y: u8,
+    },
+    /// The column with the given x coordinate (0-8).
+    Column {
+        /// The x coordinate of the column.
+        x: u8,
+    },
+    /// The 3×3 box with the given box index (0-8).
+    Box {
+        /// The box index (see [`Position::box_index`]).
+        index: u8,
+    },
+}
+
+impl House {
+    /// All 27 houses: rows 0-8, then columns 0-8, then boxes 0-8.
+    pub const ALL: [House; 27] = {
+        let mut houses = [House::Row { y: 0 }; 27];
+        let mut i = 0;
+        while i < 9 {
+            houses[i as usize] = House::Row { y: i };
+            houses[i as usize + 9] = House::Column { x: i };
+            houses[i as usize + 18] = House::Box { index: i };
+            i += 1;
+        }
+        houses
+    };
+
+    /// Returns the position of the cell with the given cell index (0-8) in this house.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_index` is greater than or equal to 9.
+    #[must_use]
+    pub const fn position(self, cell_index: u8) -> Position {
+        match self {
+            House::Row { y } => Position::new(cell_index, y),
+            House::Column { x } => Position::new(x, cell_index),
+            House::Box { index } => Position::from_box(index, cell_index),
+        }
+    }
+
+    /// Returns the cell index (0-8) of `pos` within this house, or `None` if
+    /// `pos` does not belong to this house.
+    #[must_use]
+    pub const fn cell_index(self, pos: Position) -> Option<u8> {
+        match self {
+            House::Row { y } if pos.y() == y => Some(pos.x()),
+            House::Column { x } if pos.x() == x => Some(pos.y()),
+            House::Box { index } if pos.box_index() == index => Some(pos.box_cell_index()),
+            _ => None,
+        }
+    }
+
+    /// Returns all nine positions in this house, ordered by cell index.
+    #[must_use]
+    pub fn cells(self) -> [Position; 9] {
+        match self {
+            House::Row { y } => Position::ROWS[y],
+            House::Column { x } => Position::COLUMNS[x],
+            House::Box { index } => Position::BOXES[index],
+        }
+    }
+
+    /// Returns the set of all nine positions in this house.
+    #[must_use]
+    pub fn positions(self) -> DigitPositions {
+        match self {
+            House::Row { y } => DigitPositions::ROW_POSITIONS[y],
+            House::Column { x } => DigitPositions::COLUMN_POSITIONS[x],
+            House::Box { index } => DigitPositions::BOX_POSITIONS[index],
+        }
+    }
+}
+
+impl Display for House {
+    /// Formats the house with a 1-based number, e.g. `row 1`, `column 9`, `box 5`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            House::Row { y } => write!(f, "row {}", y + 1),
+            House::Column { x } => write!(f, "column {}", x + 1),
+            House::Box { index } => write!(f, "box {}", index + 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_and_cell_index_roundtrip() {
+        for house in House::ALL {
+            for cell_index in 0..9 {
+                let pos = house.position(cell_index);
+                assert_eq!(house.cell_index(pos), Some(cell_index));
+                assert!(house.positions().contains(pos));
+                assert_eq!(house.cells()[usize::from(cell_index)], pos);
+            }
+            assert_eq!(house.positions().len(), 9);
+        }
+
+        assert_eq!(House::Row { y: 2 }.cell_index(Position::new(4, 3)), None);
+        assert_eq!(House::Column { x: 2 }.cell_index(Position::new(4, 3)), None);
+        assert_eq!(
+            House::Box { index: 0 }.cell_index(Position::new(4, 3)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_all_covers_each_position_three_times() {
+        for pos in Position::ALL {
+            let count = House::ALL
+                .iter()
+                .filter(|house| house.positions().contains(pos))
+                .count();
+            assert_eq!(count, 3);
+        }
+    }
+
+    #[test]
+    fn test_house_mask_matches_cell_index() {
+        let positions = DigitPositions::from_iter([Position::new(4, 3), Position::new(5, 4)]);
+        for house in House::ALL {
+            let mask = positions.house_mask(house);
+            for pos in positions {
+                assert_eq!(
+                    house.cell_index(pos).is_some_and(|i| mask.contains(i)),
+                    house.positions().contains(pos)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(House::Row { y: 0 }.to_string(), "row 1");
+        assert_eq!(House::Column { x: 8 }.to_string(), "column 9");
+        assert_eq!(House::Box { index: 4 }.to_string(), "box 5");
+    }
+}
@@ -8,7 +8,7 @@
 //!
 //! This crate provides fundamental types and data structures:
 //!
-//! - **Basic Types**: [`Digit`] (1-9), [`Position`] (grid coordinates)
+//! - **Basic Types**: [`Digit`] (1-9), [`Position`] (grid coordinates), [`House`] (row, column, or box)
 //! - **Grid Types**: [`CandidateGrid`] (digit-centric), [`DigitGrid`] (cell-centric)
 //! - **Type Aliases**: [`DigitSet`], [`DigitPositions`], [`HouseMask`]
 //! - **Generic Infrastructure**: [`BitSet9`], [`BitSet81`], [`Array9`], [`Array81`]
@@ -225,8 +225,9 @@ mod candidate_grid;
 pub mod containers;
 mod digit;
 mod digit_grid;
+mod house;
 pub mod index;
 mod position;
 
 // Re-export commonly used types
-pub use self::{candidate_grid::*, digit::*, digit_grid::*, position::*};
+pub use self::{candidate_grid::*, digit::*, digit_grid::*, house::*, position::*};
@@ -1,5 +1,7 @@
 //! Board position types.
 
+use std::fmt::{self, Display};
+
 use crate::{DigitPositions, containers::Array9, index::CellIndexSemantics};
 
 /// Board position (x, y) where x is column and y is row.
@@ -392,6 +394,13 @@ impl Position {
     }
 }
 
+impl Display for Position {
+    /// Formats the position with a 1-based row and column, e.g. `r1c9`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "r{}c{}", self.y + 1, self.x + 1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -417,6 +426,12 @@ mod tests {
         assert_eq!(Position::new(8, 8).box_cell_index(), 8);
     }
 
+    #[test]
+    fn test_display() {
+        assert_eq!(Position::new(0, 0).to_string(), "r1c1");
+        assert_eq!(Position::new(8, 2).to_string(), "r3c9");
+    }
+
     #[test]
     #[should_panic(expected = "assertion failed")]
     fn test_new_position_x_too_large() {
@@ -4,6 +4,7 @@ use numelace_core::{
     index::{DigitSemantics, PositionSemantics},
 };
 use numelace_generator::GeneratedPuzzle;
+use numelace_solver::{NotesCheck, NotesChecker, SolverError, TechniqueSolver};
 
 use crate::{
     CellState, GameError, InputBlockReason, InputDigitOptions, InputOperation, RuleCheckPolicy,
@@ -188,6 +189,52 @@ impl Game {
         candidate_grid
     }
 
+    /// Checks the player's fills and notes against the solution of the givens.
+    ///
+    /// Filled digits that differ from the solution are reported as wrong.
+    /// `solver` propagates the givens and the other fills: notes it eliminates
+    /// are reported as dead, as well as solution digits missing from the notes
+    /// of a cell. Cells without notes are not checked. See [`NotesChecker`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Inconsistent`] if the givens conflict, or the
+    /// solver finds a contradiction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use numelace_game::Game;
+    /// use numelace_generator::PuzzleGenerator;
+    /// use numelace_solver::TechniqueSolver;
+    ///
+    /// let solver = TechniqueSolver::with_all_techniques();
+    /// let generator = PuzzleGenerator::new(&solver);
+    /// let mut game = Game::new(generator.generate());
+    /// game.auto_fill_notes_all_cells();
+    ///
+    /// // Notes of every digit not seen in a peer are never wrong
+    /// let check = game.check_notes(&TechniqueSolver::new(vec![]))?;
+    /// assert!(check.is_correct());
+    /// # Ok::<(), numelace_solver::SolverError>(())
+    /// ```
+    pub fn check_notes(&self, solver: &TechniqueSolver) -> Result<NotesCheck, SolverError> {
+        let mut givens = DigitGrid::new();
+        let mut fills = DigitGrid::new();
+        let mut notes = CandidateGrid::new();
+        for pos in Position::ALL {
+            let cell_notes = self.grid[pos].as_notes().unwrap_or(DigitSet::EMPTY);
+            for digit in Digit::ALL {
+                if !cell_notes.contains(digit) {
+                    notes.remove_candidate(pos, digit);
+                }
+            }
+            givens.set(pos, self.grid[pos].as_given());
+            fills.set(pos, self.grid[pos].as_filled());
+        }
+        NotesChecker::new(solver).check(&givens, &fills, &notes)
+    }
+
     fn is_conflicting(&self, pos: Position, digit: Digit) -> bool {
         for peer_pos in pos.house_peers() {
             if self.grid[peer_pos].as_digit() == Some(digit) {
@@ -1026,4 +1073,52 @@ mod tests {
         // Not solved due to conflicts
         assert!(!game.is_solved());
     }
+
+    #[test]
+    fn test_check_notes_reports_mistakes() {
+        let problem: DigitGrid =
+            "...36..4..9.....2...67..1..5....987..31..............4..8...65.67.....3......3..2"
+                .parse()
+                .expect("valid problem grid");
+        let filled: DigitGrid = format!(".8{}", ".".repeat(79))
+            .parse()
+            .expect("valid filled grid");
+        let mut game = Game::from_problem_filled_notes(&problem, &filled, &[[0; 9]; 9])
+            .expect("compatible grids");
+        let solver = TechniqueSolver::new(vec![]);
+        assert!(game.check_notes(&solver).unwrap().is_correct());
+
+        // The solution digit of r1c1 is 1; 3 is given in r1c4 and 8 filled in r1c2
+        let pos = Position::new(0, 0);
+        for digit in [Digit::D2, Digit::D3, Digit::D8] {
+            game.toggle_note(pos, digit, RuleCheckPolicy::Permissive)
+                .unwrap();
+        }
+        let check = game.check_notes(&solver).unwrap();
+        assert_eq!(
+            check.wrongly_eliminated(),
+            [numelace_solver::Candidate::new(pos, Digit::D1)]
+        );
+        assert_eq!(check.dead().len(), 2);
+
+        // A wrong fill is reported without failing the check
+        game.set_digit(
+            Position::new(2, 0),
+            Digit::D8,
+            &InputDigitOptions::default(),
+        )
+        .unwrap();
+        let check = game.check_notes(&solver).unwrap();
+        assert_eq!(
+            check.wrong_fills(),
+            [numelace_solver::Candidate::new(
+                Position::new(2, 0),
+                Digit::D8
+            )]
+        );
+        assert_eq!(
+            check.wrongly_eliminated(),
+            [numelace_solver::Candidate::new(pos, Digit::D1)]
+        );
+    }
 }
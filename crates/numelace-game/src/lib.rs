@@ -23,6 +23,9 @@
 //!   and there are no rule violations (accepts any valid solution).
 //! - **Cell state tracking**: Uses [`CellState`] enum to distinguish between given,
 //!   filled, and empty cells at the type level.
+//! - **Notes checking**: [`Game::check_notes`] compares the player's fills with the
+//!   solution and the notes with the candidates a solver leaves, for a "check my
+//!   notes" feature; the solver passed in decides how much logic counts as obvious.
 //!
 //! # Examples
 //!
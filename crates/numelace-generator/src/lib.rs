@@ -19,7 +19,8 @@
 //!    using a hybrid approach of random placement and backtracking with solver assistance.
 //!
 //! 2. **Remove cells**: Attempts to remove as many cells as possible while maintaining
-//!    unique solvability. The removal is verified using [`TechniqueSolver`] from the
+//!    unique solvability. Each removal is first checked for a unique solution with the
+//!    fast [`BitboardSolver`], then verified using [`TechniqueSolver`] from the
 //!    `numelace-solver` crate, which ensures the puzzle can be solved using only human-like
 //!    logical deduction techniques.
 //!
@@ -109,7 +110,9 @@
 use std::{fmt::Display, str::FromStr};
 
 use numelace_core::{CandidateGrid, Digit, DigitGrid, Position};
-use numelace_solver::{TechniqueSolver, backtrack};
+use numelace_solver::{
+    BitboardSolver, SolveControl, SolverError, TechniqueSolver, TechniqueSolverStats, backtrack,
+};
 use rand::{
     Rng, SeedableRng,
     distr::{Distribution, StandardUniform},
@@ -192,14 +195,57 @@ impl<'a> PuzzleGenerator<'a> {
     /// ```
     #[must_use]
     pub fn generate_with_seed(&self, seed: PuzzleSeed) -> GeneratedPuzzle {
+        self.generate_with_control(seed, &SolveControl::new())
+            .unwrap_or_else(|_| unreachable!("generation without limits cannot be interrupted"))
+    }
+
+    /// Generates a puzzle with a specific seed, within the limits of `control`.
+    ///
+    /// The technique steps of every solve made during generation, and the
+    /// backtracks while filling the solution grid, are counted in `control`.
+    /// A generation takes thousands of steps, so step limits should be set
+    /// accordingly; a deadline or the cancel flag is usually the better fit.
+    ///
+    /// When not interrupted, the puzzle is the same as the one
+    /// [`generate_with_seed`](Self::generate_with_seed) returns for `seed`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Interrupted`] if a limit of `control` is hit or
+    /// generation is cancelled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use numelace_generator::{PuzzleGenerator, PuzzleSeed};
+    /// use numelace_solver::{SolveControl, SolverError, TechniqueSolver};
+    ///
+    /// let solver = TechniqueSolver::with_all_techniques();
+    /// let generator = PuzzleGenerator::new(&solver);
+    /// let seed = PuzzleSeed::from([7u8; 32]);
+    ///
+    /// let control = SolveControl::new().time_limit(Duration::from_secs(60));
+    /// match generator.generate_with_control(seed, &control) {
+    ///     Ok(puzzle) => assert_eq!(puzzle.problem, generator.generate_with_seed(seed).problem),
+    ///     Err(SolverError::Interrupted(reason)) => println!("Gave up: {reason}"),
+    ///     Err(err) => panic!("unexpected error: {err}"),
+    /// }
+    /// ```
+    pub fn generate_with_control(
+        &self,
+        seed: PuzzleSeed,
+        control: &SolveControl,
+    ) -> Result<GeneratedPuzzle, SolverError> {
         let mut rng = Pcg64::from_seed(seed.0);
-        let solution = self.generate_solution(&mut rng);
-        let problem = self.remove_cells(&mut rng, &solution);
-        GeneratedPuzzle {
+        let solution = self.generate_solution(&mut rng, control)?;
+        let problem = self.remove_cells(&mut rng, &solution, control)?;
+        Ok(GeneratedPuzzle {
             problem,
             solution,
             seed,
-        }
+        })
     }
 
     /// Generates a complete, valid Sudoku solution grid.
@@ -212,7 +258,11 @@ impl<'a> PuzzleGenerator<'a> {
     /// The backtracking uses [`numelace_solver::backtrack::find_best_assumption`] to
     /// select cells with minimum candidates (MRV heuristic), and the solver is used
     /// to eliminate obviously impossible candidates, making the search more efficient.
-    fn generate_solution<R>(&self, rng: &mut R) -> DigitGrid
+    fn generate_solution<R>(
+        &self,
+        rng: &mut R,
+        control: &SolveControl,
+    ) -> Result<DigitGrid, SolverError>
     where
         R: Rng,
     {
@@ -250,11 +300,21 @@ impl<'a> PuzzleGenerator<'a> {
             stack.push((grid.clone(), (pos, digits)));
             grid.place(pos, digit);
             // Use the solver to fill in cells that can be determined logically
-            let Ok((solved, _)) = self.solver.solve(&mut grid) else {
-                continue; // Contradiction found, backtrack
+            let mut stats = TechniqueSolverStats::new();
+            let solved = match self
+                .solver
+                .solve_with_control(&mut grid, &mut stats, control)
+            {
+                Ok(solved) => solved,
+                Err(SolverError::Inconsistent(_)) => {
+                    // Contradiction found, backtrack
+                    control.record_backtrack()?;
+                    continue;
+                }
+                Err(err) => return Err(err),
             };
             if solved {
-                return grid.to_digit_grid();
+                return Ok(grid.to_digit_grid());
             }
             // Pick the next cell to fill
             let assumption = backtrack::find_best_assumption(&grid);
@@ -270,12 +330,22 @@ impl<'a> PuzzleGenerator<'a> {
     ///
     /// 1. Shuffle all 81 cell positions
     /// 2. For each position, try removing the cell
-    /// 3. Verify the puzzle is still solvable using `TechniqueSolver`
-    /// 4. If solvable, keep the cell removed; otherwise, restore it
+    /// 3. Check that the puzzle still has a unique solution using [`BitboardSolver`]
+    /// 4. Verify the puzzle is still solvable using `TechniqueSolver`
+    /// 5. If both hold, keep the cell removed; otherwise, restore it
+    ///
+    /// The uniqueness check is redundant with the technique solver, but far
+    /// cheaper on the ambiguous grids where the solver would apply every
+    /// technique before getting stuck.
     ///
     /// The resulting puzzle has the maximum number of removed cells while
     /// maintaining a unique solution that can be found using human-like techniques.
-    fn remove_cells<R>(&self, rng: &mut R, solution: &DigitGrid) -> DigitGrid
+    fn remove_cells<R>(
+        &self,
+        rng: &mut R,
+        solution: &DigitGrid,
+        control: &SolveControl,
+    ) -> Result<DigitGrid, SolverError>
     where
         R: Rng,
     {
@@ -286,12 +356,20 @@ impl<'a> PuzzleGenerator<'a> {
             let mut removed = problem.clone();
             removed.set(pos, None);
             let mut test_grid = CandidateGrid::from_digit_grid(&removed);
-            let result = self.solver.solve(&mut test_grid);
-            if result.is_ok_and(|(solved, _)| solved) {
-                problem = removed;
+            if !BitboardSolver::new().is_unique(&test_grid) {
+                continue;
+            }
+            let mut stats = TechniqueSolverStats::new();
+            match self
+                .solver
+                .solve_with_control(&mut test_grid, &mut stats, control)
+            {
+                Ok(true) => problem = removed,
+                Ok(false) | Err(SolverError::Inconsistent(_)) => {}
+                Err(err) => return Err(err),
             }
         }
-        problem
+        Ok(problem)
     }
 }
 
@@ -385,6 +463,7 @@ pub struct GeneratedPuzzle {
 #[cfg(test)]
 mod tests {
     use numelace_core::DigitSet;
+    use numelace_solver::Interruption;
 
     use super::*;
 
@@ -393,7 +472,9 @@ mod tests {
         let solver = TechniqueSolver::with_all_techniques();
         let generator = PuzzleGenerator::new(&solver);
         let mut rng = Pcg64::from_seed([1u8; 32]);
-        let solution = generator.generate_solution(&mut rng);
+        let solution = generator
+            .generate_solution(&mut rng, &SolveControl::new())
+            .unwrap();
 
         // All cells should be filled
         for pos in Position::ALL {
@@ -409,7 +490,9 @@ mod tests {
         let solver = TechniqueSolver::with_all_techniques();
         let generator = PuzzleGenerator::new(&solver);
         let mut rng = Pcg64::from_seed([2u8; 32]);
-        let solution = generator.generate_solution(&mut rng);
+        let solution = generator
+            .generate_solution(&mut rng, &SolveControl::new())
+            .unwrap();
 
         // Check all rows have digits 1-9
         for row in 0..9 {
@@ -464,10 +547,14 @@ mod tests {
         let seed = [42u8; 32];
 
         let mut rng1 = Pcg64::from_seed(seed);
-        let solution1 = generator.generate_solution(&mut rng1);
+        let solution1 = generator
+            .generate_solution(&mut rng1, &SolveControl::new())
+            .unwrap();
 
         let mut rng2 = Pcg64::from_seed(seed);
-        let solution2 = generator.generate_solution(&mut rng2);
+        let solution2 = generator
+            .generate_solution(&mut rng2, &SolveControl::new())
+            .unwrap();
 
         // Same seed should produce identical solutions
         assert_eq!(solution1, solution2);
@@ -479,10 +566,14 @@ mod tests {
         let generator = PuzzleGenerator::new(&solver);
 
         let mut rng1 = Pcg64::from_seed([1u8; 32]);
-        let solution1 = generator.generate_solution(&mut rng1);
+        let solution1 = generator
+            .generate_solution(&mut rng1, &SolveControl::new())
+            .unwrap();
 
         let mut rng2 = Pcg64::from_seed([2u8; 32]);
-        let solution2 = generator.generate_solution(&mut rng2);
+        let solution2 = generator
+            .generate_solution(&mut rng2, &SolveControl::new())
+            .unwrap();
 
         // Different seeds should (almost certainly) produce different solutions
         assert_ne!(solution1, solution2);
@@ -493,7 +584,9 @@ mod tests {
         let solver = TechniqueSolver::with_all_techniques();
         let generator = PuzzleGenerator::new(&solver);
         let mut rng = Pcg64::from_seed([5u8; 32]);
-        let solution = generator.generate_solution(&mut rng);
+        let solution = generator
+            .generate_solution(&mut rng, &SolveControl::new())
+            .unwrap();
 
         // Verify the solution by placing it in a CandidateGrid
         let mut candidate_grid = CandidateGrid::new();
@@ -514,10 +607,14 @@ mod tests {
         let mut rng = Pcg64::from_seed([42u8; 32]);
 
         // Generate a complete solution
-        let solution = generator.generate_solution(&mut rng);
+        let solution = generator
+            .generate_solution(&mut rng, &SolveControl::new())
+            .unwrap();
 
         // Remove cells
-        let problem = generator.remove_cells(&mut rng, &solution);
+        let problem = generator
+            .remove_cells(&mut rng, &solution, &SolveControl::new())
+            .unwrap();
 
         // Solve the problem
         let mut test_grid = CandidateGrid::from_digit_grid(&problem);
@@ -540,8 +637,12 @@ mod tests {
         let generator = PuzzleGenerator::new(&solver);
         let mut rng = Pcg64::from_seed([100u8; 32]);
 
-        let solution = generator.generate_solution(&mut rng);
-        let problem = generator.remove_cells(&mut rng, &solution);
+        let solution = generator
+            .generate_solution(&mut rng, &SolveControl::new())
+            .unwrap();
+        let problem = generator
+            .remove_cells(&mut rng, &solution, &SolveControl::new())
+            .unwrap();
 
         // Count removed cells
         let removed_count = Position::ALL
@@ -559,8 +660,12 @@ mod tests {
         let generator = PuzzleGenerator::new(&solver);
         let mut rng = Pcg64::from_seed([7u8; 32]);
 
-        let solution = generator.generate_solution(&mut rng);
-        let problem = generator.remove_cells(&mut rng, &solution);
+        let solution = generator
+            .generate_solution(&mut rng, &SolveControl::new())
+            .unwrap();
+        let problem = generator
+            .remove_cells(&mut rng, &solution, &SolveControl::new())
+            .unwrap();
 
         // Every filled cell in problem should match the solution
         for pos in Position::ALL {
@@ -574,6 +679,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_generate_with_control_stops_at_limits() {
+        let solver = TechniqueSolver::with_all_techniques();
+        let generator = PuzzleGenerator::new(&solver);
+        let seed = PuzzleSeed::from([3u8; 32]);
+
+        let control = SolveControl::new();
+        control.cancel();
+        assert!(matches!(
+            generator.generate_with_control(seed, &control),
+            Err(SolverError::Interrupted(Interruption::Cancelled))
+        ));
+
+        let control = SolveControl::new().max_steps(10);
+        assert!(matches!(
+            generator.generate_with_control(seed, &control),
+            Err(SolverError::Interrupted(Interruption::StepLimit))
+        ));
+        assert_eq!(control.steps(), 10);
+    }
+
     #[test]
     fn test_puzzle_seed_from_str_valid() {
         // Test various valid hex cases (lowercase, uppercase, mixed)
@@ -2,7 +2,11 @@
 //!
 //! This benchmark suite measures the performance of backtracking operations,
 //! particularly the `find_best_assumption` function which selects the next cell
-//! to try when backtracking is needed.
+//! to try when backtracking is needed. It also compares full searches of
+//! `BacktrackSolver`, `DlxSolver` and `BitboardSolver`: finding the first
+//! solution, and counting up to two solutions as a uniqueness check does.
+//! Finally, it measures the uniqueness check throughput of `DlxSolver` and
+//! `BitboardSolver` over a list of generated puzzles, in puzzles per second.
 //!
 //! # Test Data
 //!
@@ -14,8 +18,10 @@
 //! - **mid** (40 given): Medium difficulty with moderate clues
 //! - **dense** (60 given): Nearly solved puzzle with many clues
 //!
-//! Each puzzle is preprocessed with `NakedSingle` constraint propagation to simulate
-//! realistic solver state.
+//! The uniqueness check list holds 32 unique puzzles with 22 to 27 givens.
+//!
+//! For `find_best_assumption`, each puzzle is preprocessed with `NakedSingle`
+//! constraint propagation to simulate realistic solver state.
 //!
 //! # Running
 //!
@@ -25,10 +31,10 @@
 
 use std::{hint, str::FromStr as _};
 
-use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
 use numelace_core::{CandidateGrid, DigitGrid};
 use numelace_solver::{
-    backtrack,
+    BacktrackSolver, BitboardSolver, DlxSolver, backtrack,
     technique::{NakedSingle, Technique as _},
 };
 
@@ -50,6 +56,43 @@ const DENSE_PROBLEM: &str =
 const _SOLUTION: &str =
     "185362947793148526246795183564239871931874265827516394318427659672951438459683712";
 
+// Unique puzzles generated from the seeds [i; 32] for i in 0..32, using
+// PuzzleGenerator with all techniques.
+const UNIQUE_PROBLEMS: [&str; 32] = [
+    "73......6....7..1....526...3...8..7......9..1.....785.56..9.2.....2..4...9.4.....",
+    ".8......3....7.5...54..217......8......9.3.86..7....5..2.8.563........1....6.9...",
+    ".2.6.....5973...2.......5..4..1.6.......97.....5.8.3....3...9.......2..67..4..25.",
+    ".9.2.36.14..7...5.6....9........2.......91.8...2685..7.4...8.7..5.....26...9.....",
+    ".6871.35....6..8.4....8....9...716.3.7.3.6......5...2....4....1..1..8....3..6.9..",
+    "....2........64.95...85....5.2..6....6......8.9.7....62..4..8..3.......9187...35.",
+    ".6...3..5.3..6.19..1..4....1.......8..79...3..5.3.4.........47....5..8.26.....9..",
+    ".3...4..698.2...51...9..........2....15...93..96.....4.47.6..1.........5...1.....",
+    "6....537.1..4.....7...............6.5.3.12.9....7..8...8....6.49.5.6........3.5..",
+    "....5..3.8.16...............36....5..2..6...4.7.394.......4917.......64...27...8.",
+    ".72.4............8.1...69..83....4...2.5.91.........8...1.9......47..52...5.6...4",
+    ".1.....4...432.61..5....7..3....4.....18...9..............6...1...2.738....4.8.29",
+    "5...7.....6.8..9.54.1..2....821..67.......4.......6.5...5.8.......369...63.......",
+    ".1.......7..4...5.....5...8..2....1......9..43..56......8..65...3.94.1..9...7.42.",
+    "...4..7...879.5........2.91278.......19....5........68.5...7......2.4..9.....8.25",
+    "...5819...9..2.7...5......6..4..8.....3.9..4...9.7..3.7...14..............8.3..9.",
+    "..9.5.....2...6.18.6.9.....5.438.29..86..1..33...9.8...3.7.......7......6......2.",
+    "9..2..1....8.......4...1..9...675.3.......4.7..........8.3.29....6.9.2...3.4..76.",
+    "........7.6.1.78.....5.84..65...2.8...2.6.9....98..7...453....22.1..6....3.......",
+    "..5...8.114.7....53..6.....7....9.58.......4..8...27...9....6.....4....7.....8.1.",
+    "4...18.9...3....7.1.836.5...6...2.37...........5...1.2..6.......2..5...6....21..5",
+    "1..2....6..5.16.......5..7....5...9...8..3...4.6.82........75..963......5..4...3.",
+    ".5..87........2.......3.7.6.1..9.....963..5.8........7..1.....453...9......7258..",
+    "1...6.8......7..6...9..21...4........1....7.22.6...58..73.9..2..2.....7...1..843.",
+    ".87..2.3...6....71..53.96.......6.4...8..39.....2...1........95..2..5....3.9.....",
+    "......2....75....932......5...8..69......34.7.9.61.5..4......8..6..8....7.5...9.6",
+    ".5...3..7..641......32........7....996..4..5.......8.3.......92.8.......7.2.9.31.",
+    ".82...........798.....59...1...9.5..3..4......6.....23.....57....3.2.......7.64..",
+    "5...6.4.99..............28....2....7..978..5.48..........1.76..63...........93..2",
+    "..2.3..9..9.8...........14.7.....85.8...96...4..57..1..........1..7..52..5..4.68.",
+    "..28.56.9........31...9....42......19...18......6...2......2....1847..6..345.....",
+    "4....36.....95.....1..2...58.3..671.......4..2..8..........8.3.....9...7.4..7..9.",
+];
+
 fn bench_find_best_assumption(c: &mut Criterion) {
     let puzzles = [
         ("empty", EMPTY_PROBLEM),
@@ -83,5 +126,121 @@ fn bench_find_best_assumption(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, bench_find_best_assumption);
+fn bench_search(c: &mut Criterion) {
+    let puzzles = [
+        ("empty", EMPTY_PROBLEM),
+        ("sparse", SPARSE_PROBLEM),
+        ("mid", MID_PROBLEM),
+        ("dense", DENSE_PROBLEM),
+    ];
+    let backtrack_solver = BacktrackSolver::with_fundamental_techniques();
+    let dlx_solver = DlxSolver::new();
+    let bitboard_solver = BitboardSolver::new();
+
+    for (param, grid) in puzzles {
+        let grid = DigitGrid::from_str(grid).unwrap();
+        let given = grid.iter().filter(|o| o.is_some()).count();
+        let grid = CandidateGrid::from(grid);
+        let param = format!("{param}_{given}");
+
+        c.bench_with_input(
+            BenchmarkId::new("backtrack_first_solution", &param),
+            &grid,
+            |b, grid| {
+                b.iter(|| {
+                    let grid = hint::black_box(grid.clone());
+                    hint::black_box(backtrack_solver.solve(grid).unwrap().next())
+                });
+            },
+        );
+        c.bench_with_input(
+            BenchmarkId::new("dlx_first_solution", &param),
+            &grid,
+            |b, grid| {
+                b.iter(|| {
+                    let grid = hint::black_box(grid);
+                    hint::black_box(dlx_solver.solve(grid).unwrap().next())
+                });
+            },
+        );
+        c.bench_with_input(
+            BenchmarkId::new("bitboard_first_solution", &param),
+            &grid,
+            |b, grid| {
+                b.iter(|| {
+                    let grid = hint::black_box(grid);
+                    hint::black_box(bitboard_solver.solve(grid).unwrap().next())
+                });
+            },
+        );
+        c.bench_with_input(
+            BenchmarkId::new("backtrack_count_solutions", &param),
+            &grid,
+            |b, grid| {
+                b.iter(|| {
+                    let grid = hint::black_box(grid);
+                    hint::black_box(backtrack_solver.count_solutions(grid, 2))
+                });
+            },
+        );
+        c.bench_with_input(
+            BenchmarkId::new("dlx_count_solutions", &param),
+            &grid,
+            |b, grid| {
+                b.iter(|| {
+                    let grid = hint::black_box(grid);
+                    hint::black_box(dlx_solver.count_solutions(grid, 2))
+                });
+            },
+        );
+        c.bench_with_input(
+            BenchmarkId::new("bitboard_count_solutions", &param),
+            &grid,
+            |b, grid| {
+                b.iter(|| {
+                    let grid = hint::black_box(grid);
+                    hint::black_box(bitboard_solver.count_solutions(grid, 2))
+                });
+            },
+        );
+    }
+}
+
+fn bench_uniqueness_throughput(c: &mut Criterion) {
+    let grids: Vec<_> = UNIQUE_PROBLEMS
+        .iter()
+        .map(|problem| CandidateGrid::from(DigitGrid::from_str(problem).unwrap()))
+        .collect();
+    let dlx_solver = DlxSolver::new();
+    let bitboard_solver = BitboardSolver::new();
+
+    let mut group = c.benchmark_group("uniqueness_throughput");
+    group.throughput(Throughput::Elements(grids.len() as u64));
+    group.bench_with_input(BenchmarkId::new("dlx", grids.len()), &grids, |b, grids| {
+        b.iter(|| {
+            for grid in hint::black_box(grids) {
+                assert!(dlx_solver.is_unique(grid));
+            }
+        });
+    });
+    group.bench_with_input(
+        BenchmarkId::new("bitboard", grids.len()),
+        &grids,
+        |b, grids| {
+            b.iter(|| {
+                for grid in hint::black_box(grids) {
+                    assert!(bitboard_solver.is_unique(grid));
+                }
+            });
+        },
+    );
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_find_best_assumption,
+    bench_search,
+    bench_uniqueness_throughput
+);
 criterion_main!(benches);
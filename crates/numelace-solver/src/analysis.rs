@@ -0,0 +1,387 @@
+//! Analysis of puzzles that a technique set cannot solve on its own.
+//!
+//! A *backdoor* is a set of cell assignments, taken from the solution, after
+//! which a [`TechniqueSolver`] finishes the puzzle. The size of the smallest
+//! backdoor measures how far the puzzle is beyond the technique set: size 0
+//! means the techniques solve it, size 1 that a single well-chosen guess is
+//! enough. The guess tree shows how much guessing a [`BacktrackSolver`]-style
+//! search needs with the same techniques.
+//!
+//! [`BacktrackSolver`]: crate::BacktrackSolver
+
+use numelace_core::{CandidateGrid, DigitGrid, Position};
+
+use crate::{
+    BitboardSolver, Candidate, SolveControl, SolverError, TechniqueSolver, TechniqueSolverStats,
+    backtrack,
+};
+
+/// The default maximum backdoor size searched by [`BackdoorAnalyzer`].
+///
+/// Searching for a backdoor of size `n` solves the puzzle once per set of `n`
+/// empty cells, so each extra assignment multiplies the cost by about the
+/// number of empty cells.
+pub const DEFAULT_MAX_BACKDOOR_SIZE: usize = 2;
+
+/// Finds backdoors and measures the guess tree of puzzles for a
+/// [`TechniqueSolver`].
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::{CandidateGrid, DigitGrid};
+/// use numelace_solver::{BackdoorAnalyzer, TechniqueSolver, technique::NakedSingle};
+///
+/// let puzzle: DigitGrid =
+///     "...36..4..9.....2...67..1..5....987..31..............4..8...65.67.....3......3..2"
+///         .parse()?;
+/// let solver = TechniqueSolver::new(vec![Box::new(NakedSingle::new())]);
+///
+/// let analysis = BackdoorAnalyzer::new(&solver).analyze(&CandidateGrid::from_digit_grid(&puzzle))?;
+/// if let Some(backdoor) = analysis.backdoor() {
+///     println!("Naked singles finish the puzzle after {} assignments", backdoor.len());
+/// }
+/// let tree = analysis.guess_tree();
+/// println!("Guess tree: depth {}, breadth {}", tree.depth(), tree.breadth());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BackdoorAnalyzer<'a> {
+    solver: &'a TechniqueSolver,
+    max_size: usize,
+}
+
+impl<'a> BackdoorAnalyzer<'a> {
+    /// Creates a new analyzer for the techniques of `solver`.
+    ///
+    /// It searches backdoors of up to [`DEFAULT_MAX_BACKDOOR_SIZE`] assignments.
+    #[must_use]
+    pub fn new(solver: &'a TechniqueSolver) -> Self {
+        Self {
+            solver,
+            max_size: DEFAULT_MAX_BACKDOOR_SIZE,
+        }
+    }
+
+    /// Sets the maximum number of assignments in a backdoor.
+    #[must_use]
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Finds a smallest backdoor of `grid` and measures its guess tree.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Inconsistent`] if the grid is inconsistent.
+    pub fn analyze(&self, grid: &CandidateGrid) -> Result<BackdoorAnalysis, SolverError> {
+        self.analyze_with_control(grid, &SolveControl::new())
+    }
+
+    /// Analyzes `grid` like [`analyze`](Self::analyze), within the limits of
+    /// `control`.
+    ///
+    /// Puzzles with many solutions have a huge guess tree, so bound their
+    /// analysis with a step limit or a deadline.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Inconsistent`] if the grid is inconsistent, and
+    /// [`SolverError::Interrupted`] if a limit of `control` is hit.
+    pub fn analyze_with_control(
+        &self,
+        grid: &CandidateGrid,
+        control: &SolveControl,
+    ) -> Result<BackdoorAnalysis, SolverError> {
+        let mut stuck = grid.clone();
+        let mut stats = TechniqueSolverStats::new();
+        if self
+            .solver
+            .solve_with_control(&mut stuck, &mut stats, control)?
+        {
+            return Ok(BackdoorAnalysis {
+                backdoor: Some(vec![]),
+                guess_tree: GuessTreeStats::default(),
+            });
+        }
+
+        let solution = BitboardSolver::new()
+            .solve(&stuck)?
+            .next()
+            .map(|solution| solution.to_digit_grid());
+        let mut backdoor = None;
+        if let Some(solution) = &solution {
+            let cells: Vec<_> = (!stuck.decided_cells()).into_iter().collect();
+            for size in 1..=self.max_size {
+                let mut assignments = vec![];
+                if self.find_backdoor(&stuck, &cells, solution, size, &mut assignments, control)? {
+                    backdoor = Some(assignments);
+                    break;
+                }
+            }
+        }
+
+        let mut guess_tree = GuessTreeStats::default();
+        self.explore(&stuck, 0, &mut guess_tree, control)?;
+        Ok(BackdoorAnalysis {
+            backdoor,
+            guess_tree,
+        })
+    }
+
+    /// Searches `size` more assignments among `cells`, in order, that let the
+    /// techniques solve `grid`.
+    ///
+    /// On success, returns `true` with the assignments appended to
+    /// `assignments`.
+    fn find_backdoor(
+        &self,
+        grid: &CandidateGrid,
+        cells: &[Position],
+        solution: &DigitGrid,
+        size: usize,
+        assignments: &mut Vec<Candidate>,
+        control: &SolveControl,
+    ) -> Result<bool, SolverError> {
+        for (i, &pos) in cells.iter().enumerate() {
+            let Some(digit) = solution.get(pos) else {
+                continue;
+            };
+            let mut next = grid.clone();
+            next.place(pos, digit);
+            let mut stats = TechniqueSolverStats::new();
+            let solved = match self
+                .solver
+                .solve_with_control(&mut next, &mut stats, control)
+            {
+                Ok(solved) => solved,
+                // Only uniqueness techniques on an ambiguous puzzle get here
+                Err(SolverError::Inconsistent(_)) => continue,
+                Err(err) => return Err(err),
+            };
+            assignments.push(Candidate::new(pos, digit));
+            if solved && size == 1 {
+                return Ok(true);
+            }
+            if !solved && size > 1 {
+                // Cells solved by this assignment would add nothing
+                let decided = next.decided_cells();
+                let rest: Vec<_> = cells[i + 1..]
+                    .iter()
+                    .copied()
+                    .filter(|&pos| !decided.contains(pos))
+                    .collect();
+                if self.find_backdoor(&next, &rest, solution, size - 1, assignments, control)? {
+                    return Ok(true);
+                }
+            }
+            assignments.pop();
+        }
+        Ok(false)
+    }
+
+    /// Tries every candidate of the best assumption cell of the stuck `grid`,
+    /// and explores the grids the techniques get stuck on again.
+    fn explore(
+        &self,
+        grid: &CandidateGrid,
+        depth: usize,
+        tree: &mut GuessTreeStats,
+        control: &SolveControl,
+    ) -> Result<(), SolverError> {
+        let (pos, digits) = backtrack::find_best_assumption(grid);
+        for digit in digits {
+            control.check()?;
+            if tree.widths.len() == depth {
+                tree.widths.push(0);
+            }
+            tree.widths[depth] += 1;
+
+            let mut next = grid.clone();
+            next.place(pos, digit);
+            let mut stats = TechniqueSolverStats::new();
+            match self
+                .solver
+                .solve_with_control(&mut next, &mut stats, control)
+            {
+                Ok(true) => tree.solutions += 1,
+                Ok(false) => self.explore(&next, depth + 1, tree, control)?,
+                Err(SolverError::Inconsistent(_)) => {
+                    tree.dead_ends += 1;
+                    control.record_backtrack()?;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The result of [`BackdoorAnalyzer::analyze`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackdoorAnalysis {
+    backdoor: Option<Vec<Candidate>>,
+    guess_tree: GuessTreeStats,
+}
+
+impl BackdoorAnalysis {
+    /// Returns a smallest backdoor, or `None` if there is none within the
+    /// maximum size or the puzzle has no solution.
+    ///
+    /// An empty backdoor means the techniques solve the puzzle. The
+    /// assignments are placements of solution digits; for a puzzle with
+    /// several solutions, they are taken from one of them.
+    #[must_use]
+    pub fn backdoor(&self) -> Option<&[Candidate]> {
+        self.backdoor.as_deref()
+    }
+
+    /// Returns the shape of the guess tree.
+    #[must_use]
+    pub fn guess_tree(&self) -> &GuessTreeStats {
+        &self.guess_tree
+    }
+}
+
+/// The shape of the search tree of a backtracking search.
+///
+/// Each node is an assumption, made on the cell with the fewest candidates
+/// once the techniques are stuck, after which the techniques run again. The
+/// tree covers the whole search, for every solution.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GuessTreeStats {
+    widths: Vec<usize>,
+    dead_ends: usize,
+    solutions: usize,
+}
+
+impl GuessTreeStats {
+    /// Returns the largest number of nested assumptions.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.widths.len()
+    }
+
+    /// Returns the largest number of assumptions at the same depth.
+    #[must_use]
+    pub fn breadth(&self) -> usize {
+        self.widths.iter().copied().max().unwrap_or(0)
+    }
+
+    /// Returns the number of assumptions at each depth, starting with the
+    /// first assumption.
+    #[must_use]
+    pub fn widths(&self) -> &[usize] {
+        &self.widths
+    }
+
+    /// Returns the total number of assumptions made.
+    #[must_use]
+    pub fn assumptions(&self) -> usize {
+        self.widths.iter().sum()
+    }
+
+    /// Returns the number of assumptions that led to a contradiction.
+    #[must_use]
+    pub fn dead_ends(&self) -> usize {
+        self.dead_ends
+    }
+
+    /// Returns the number of assumptions that led to a solution.
+    #[must_use]
+    pub fn solutions(&self) -> usize {
+        self.solutions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BacktrackSolver, Interruption,
+        technique::{HiddenSingle, NakedSingle},
+    };
+
+    const SPARSE_PROBLEM: &str =
+        "...36..4..9.....2...67..1..5....987..31..............4..8...65.67.....3......3..2";
+    const EASTER_MONSTER: &str =
+        "1.......2.9.4...5...6...7...5.9.3.......7.......85..4.7.....6...3...9.8...2.....1";
+
+    fn grid(s: &str) -> CandidateGrid {
+        CandidateGrid::from_digit_grid(&s.parse().unwrap())
+    }
+
+    #[test]
+    fn test_solvable_puzzle_has_empty_backdoor() {
+        let solver = TechniqueSolver::with_fundamental_techniques();
+        let analysis = BackdoorAnalyzer::new(&solver)
+            .analyze(&grid(SPARSE_PROBLEM))
+            .unwrap();
+        assert_eq!(analysis.backdoor(), Some(&[][..]));
+        assert_eq!(analysis.guess_tree(), &GuessTreeStats::default());
+        assert_eq!(analysis.guess_tree().depth(), 0);
+        assert_eq!(analysis.guess_tree().breadth(), 0);
+    }
+
+    #[test]
+    fn test_backdoor_lets_techniques_finish() {
+        let solver = TechniqueSolver::new(vec![Box::new(NakedSingle::new())]);
+        let puzzle = grid(SPARSE_PROBLEM);
+        let analysis = BackdoorAnalyzer::new(&solver).analyze(&puzzle).unwrap();
+        let backdoor = analysis.backdoor().unwrap();
+        assert_ne!(backdoor, []);
+
+        let mut grid = puzzle.clone();
+        for candidate in backdoor {
+            grid.place(candidate.pos(), candidate.digit());
+        }
+        assert!(solver.solve(&mut grid).unwrap().0);
+
+        // No smaller backdoor exists
+        let smaller = BackdoorAnalyzer::new(&solver)
+            .max_size(backdoor.len() - 1)
+            .analyze(&puzzle)
+            .unwrap();
+        assert_eq!(smaller.backdoor(), None);
+        assert_eq!(smaller.guess_tree(), analysis.guess_tree());
+    }
+
+    #[test]
+    fn test_guess_tree_of_unique_puzzle() {
+        let solver = TechniqueSolver::new(vec![
+            Box::new(NakedSingle::new()),
+            Box::new(HiddenSingle::new()),
+        ]);
+        let puzzle = grid(EASTER_MONSTER);
+        let analysis = BackdoorAnalyzer::new(&solver)
+            .max_size(1)
+            .analyze(&puzzle)
+            .unwrap();
+        let tree = analysis.guess_tree();
+        assert_eq!(tree.solutions(), 1);
+        assert!(tree.dead_ends() > 0);
+        assert!(tree.breadth() >= tree.widths()[0]);
+        assert_eq!(tree.assumptions(), tree.widths().iter().sum());
+
+        // The backtracking solver walks a path of the same tree
+        let (_, stats) = BacktrackSolver::new(solver)
+            .solve(puzzle)
+            .unwrap()
+            .next()
+            .unwrap();
+        assert!(stats.assumptions().len() <= tree.depth());
+    }
+
+    #[test]
+    fn test_control_bounds_ambiguous_puzzles() {
+        let solver = TechniqueSolver::with_fundamental_techniques();
+        let control = SolveControl::new().max_steps(1000);
+        let result =
+            BackdoorAnalyzer::new(&solver).analyze_with_control(&CandidateGrid::new(), &control);
+        assert!(matches!(
+            result,
+            Err(SolverError::Interrupted(Interruption::StepLimit))
+        ));
+    }
+}
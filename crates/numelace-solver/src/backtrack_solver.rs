@@ -4,10 +4,11 @@
 //! When techniques cannot make further progress, the solver makes assumptions and explores
 //! the search space to find solutions.
 
-use numelace_core::{CandidateGrid, Digit, DigitSet, Position};
+use numelace_core::{CandidateGrid, Digit, DigitGrid, DigitSet, Position};
 
 use crate::{
-    SolverError, TechniqueSolver, TechniqueSolverStats, backtrack, technique::BoxedTechnique,
+    SolveControl, SolverError, TechniqueSolver, TechniqueSolverStats, backtrack, solutions,
+    technique::BoxedTechnique,
 };
 
 /// Statistics collected during backtracking solving.
@@ -104,13 +105,9 @@ impl BacktrackSolverStats {
 /// let solver = BacktrackSolver::with_all_techniques();
 /// let grid = CandidateGrid::new();
 ///
-/// // Check for unique solution
-/// let solutions: Vec<_> = solver.solve(grid)?.take(2).collect();
-/// match solutions.len() {
-///     0 => println!("No solution"),
-///     1 => println!("Unique solution"),
-///     _ => println!("Multiple solutions"),
-/// }
+/// // Collect up to three solutions
+/// let solutions: Vec<_> = solver.solve(grid)?.take(3).collect();
+/// println!("Found {} solutions", solutions.len());
 /// # Ok::<(), numelace_solver::SolverError>(())
 /// ```
 
@@ -204,38 +201,232 @@ impl BacktrackSolver {
     /// }
     /// # Ok::<(), numelace_solver::SolverError>(())
     /// ```
-    pub fn solve(&self, mut grid: CandidateGrid) -> Result<Solutions<'_>, SolverError> {
-        let mut stats = BacktrackSolverStats::new();
-        let solved = self.solve_by_technique(&mut grid, &mut stats)?;
-        let solutions = if solved {
-            Solutions::solved(self, grid, stats)
-        } else {
-            let assumption = backtrack::find_best_assumption(&grid);
-            Solutions::with_assumptions(self, grid, stats, assumption)
-        };
-        Ok(solutions)
+    pub fn solve(&self, grid: CandidateGrid) -> Result<Solutions<'_>, SolverError> {
+        let search = Search::new(self, grid, SolveControl::new())?;
+        Ok(Solutions { search })
+    }
+
+    /// Solves the puzzle within the limits of `control` and returns an iterator
+    /// over all solutions.
+    ///
+    /// Technique steps and backtracks of the whole search, across all solutions,
+    /// are counted in `control`. The iterator yields
+    /// [`SolverError::Interrupted`] when a limit is hit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Inconsistent`] if the initial grid is inconsistent,
+    /// and [`SolverError::Interrupted`] if a limit is hit while applying
+    /// techniques to the initial grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_core::CandidateGrid;
+    /// use numelace_solver::{BacktrackSolver, SolveControl, SolverError};
+    ///
+    /// let solver = BacktrackSolver::with_all_techniques();
+    /// let control = SolveControl::new().max_backtracks(100);
+    ///
+    /// let mut solutions = solver.solve_with_control(CandidateGrid::new(), &control)?;
+    /// match solutions.next() {
+    ///     Some(Ok((solution, _))) => assert!(solution.is_solved()?),
+    ///     None => println!("No solution exists"),
+    ///     Some(Err(SolverError::Interrupted(reason))) => println!("Gave up: {reason}"),
+    ///     Some(Err(err)) => return Err(err),
+    /// }
+    /// # Ok::<(), SolverError>(())
+    /// ```
+    pub fn solve_with_control(
+        &self,
+        grid: CandidateGrid,
+        control: &SolveControl,
+    ) -> Result<BoundedSolutions<'_>, SolverError> {
+        let search = Search::new(self, grid, control.clone())?;
+        Ok(BoundedSolutions { search })
+    }
+
+    /// Counts the solutions of the puzzle, stopping once `limit` are found.
+    ///
+    /// Returns a number in `0..=limit`. An inconsistent grid has no solution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_core::CandidateGrid;
+    /// use numelace_solver::BacktrackSolver;
+    ///
+    /// let solver = BacktrackSolver::with_all_techniques();
+    ///
+    /// // An empty grid has far more than 10 solutions
+    /// assert_eq!(solver.count_solutions(&CandidateGrid::new(), 10), 10);
+    /// ```
+    #[must_use]
+    pub fn count_solutions(&self, grid: &CandidateGrid, limit: usize) -> usize {
+        self.count_solutions_with_control(grid, limit, &SolveControl::new())
+            .unwrap_or_else(|_| unreachable!("a search without limits cannot be interrupted"))
+    }
+
+    /// Counts the solutions of the puzzle within the limits of `control`,
+    /// stopping once `limit` are found.
+    ///
+    /// Returns a number in `0..=limit`. An inconsistent grid has no solution.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Interrupted`] if a limit of `control` is hit
+    /// before the count is known.
+    pub fn count_solutions_with_control(
+        &self,
+        grid: &CandidateGrid,
+        limit: usize,
+        control: &SolveControl,
+    ) -> Result<usize, SolverError> {
+        match self.solve_with_control(grid.clone(), control) {
+            Ok(solutions) => solutions::count_solutions(solutions, limit),
+            Err(SolverError::Inconsistent(_)) => Ok(0),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Checks whether the puzzle has exactly one solution.
+    ///
+    /// The search stops as soon as a second solution is found. An inconsistent
+    /// grid has no solution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_core::CandidateGrid;
+    /// use numelace_solver::{BacktrackSolver, Uniqueness};
+    ///
+    /// let solver = BacktrackSolver::with_all_techniques();
+    ///
+    /// match solver.uniqueness(&CandidateGrid::new()) {
+    ///     Uniqueness::NoSolution => println!("No solution"),
+    ///     Uniqueness::Unique(solution) => println!("Unique solution:\n{solution}"),
+    ///     Uniqueness::Multiple => println!("Multiple solutions"),
+    /// }
+    /// ```
+    #[must_use]
+    pub fn uniqueness(&self, grid: &CandidateGrid) -> Uniqueness {
+        self.uniqueness_with_control(grid, &SolveControl::new())
+            .unwrap_or_else(|_| unreachable!("a search without limits cannot be interrupted"))
+    }
+
+    /// Checks whether the puzzle has exactly one solution, within the limits of
+    /// `control`.
+    ///
+    /// The search stops as soon as a second solution is found. An inconsistent
+    /// grid has no solution.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Interrupted`] if a limit of `control` is hit
+    /// before the answer is known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_core::CandidateGrid;
+    /// use numelace_solver::{BacktrackSolver, SolveControl, SolverError};
+    ///
+    /// let solver = BacktrackSolver::without_techniques();
+    /// let control = SolveControl::new();
+    /// control.cancel();
+    ///
+    /// // An interrupted search tells nothing about the solutions
+    /// assert!(matches!(
+    ///     solver.uniqueness_with_control(&CandidateGrid::new(), &control),
+    ///     Err(SolverError::Interrupted(_))
+    /// ));
+    /// ```
+    pub fn uniqueness_with_control(
+        &self,
+        grid: &CandidateGrid,
+        control: &SolveControl,
+    ) -> Result<Uniqueness, SolverError> {
+        match self.solve_with_control(grid.clone(), control) {
+            Ok(solutions) => solutions::uniqueness(solutions),
+            Err(SolverError::Inconsistent(_)) => Ok(Uniqueness::NoSolution),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Returns `true` if the puzzle has exactly one solution.
+    ///
+    /// See [`uniqueness`](Self::uniqueness) to also get the solution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_core::CandidateGrid;
+    /// use numelace_solver::BacktrackSolver;
+    ///
+    /// let solver = BacktrackSolver::with_all_techniques();
+    /// assert!(!solver.is_unique(&CandidateGrid::new()));
+    /// ```
+    #[must_use]
+    pub fn is_unique(&self, grid: &CandidateGrid) -> bool {
+        matches!(self.uniqueness(grid), Uniqueness::Unique(_))
     }
 
     fn solve_by_technique(
         &self,
         grid: &mut CandidateGrid,
         stats: &mut BacktrackSolverStats,
+        control: &SolveControl,
     ) -> Result<bool, SolverError> {
         let solved = self
             .technique
-            .solve_with_stats(grid, &mut stats.technique)?;
+            .solve_with_control(grid, &mut stats.technique, control)?;
         Ok(solved)
     }
 }
 
+/// Whether a puzzle has no solution, exactly one, or several.
+///
+/// Returned by [`BacktrackSolver::uniqueness`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Uniqueness {
+    /// The puzzle has no solution.
+    NoSolution,
+    /// The puzzle has exactly one solution.
+    Unique(DigitGrid),
+    /// The puzzle has more than one solution.
+    Multiple,
+}
+
 /// An iterator over solutions to a Sudoku puzzle.
 ///
 /// Created by [`BacktrackSolver::solve`]. Yields solutions along with statistics
 /// about how each solution was found.
-
 #[derive(Debug, Clone)]
 pub struct Solutions<'a> {
+    search: Search<'a>,
+}
+
+/// An iterator over solutions to a Sudoku puzzle, within the limits of a
+/// [`SolveControl`].
+///
+/// Created by [`BacktrackSolver::solve_with_control`]. Yields solutions along
+/// with statistics about how each solution was found, or
+/// [`SolverError::Interrupted`] when a limit is hit or the search is cancelled.
+///
+/// An interrupted search can be resumed: once the cause is lifted (for example,
+/// the cancel flag is cleared), the next call continues where it stopped.
+/// Until then, each call yields the error again. A clone continues the search
+/// independently, but shares the control.
+#[derive(Debug, Clone)]
+pub struct BoundedSolutions<'a> {
+    search: Search<'a>,
+}
+
+/// The depth-first search behind [`Solutions`] and [`BoundedSolutions`].
+#[derive(Debug, Clone)]
+struct Search<'a> {
     solver: &'a BacktrackSolver,
+    control: SolveControl,
     stack: Vec<SearchState>,
 }
 
@@ -268,42 +459,44 @@ impl SearchState {
     }
 }
 
-impl<'a> Solutions<'a> {
-    fn solved(
+impl<'a> Search<'a> {
+    fn new(
         solver: &'a BacktrackSolver,
-        grid: CandidateGrid,
-        stats: BacktrackSolverStats,
-    ) -> Self {
-        Self {
-            solver,
-            stack: vec![SearchState::solved(grid, stats)],
-        }
-    }
-
-    fn with_assumptions(
-        solver: &'a BacktrackSolver,
-        grid: CandidateGrid,
-        stats: BacktrackSolverStats,
-        assumption: (Position, DigitSet),
-    ) -> Self {
-        Self {
+        mut grid: CandidateGrid,
+        control: SolveControl,
+    ) -> Result<Self, SolverError> {
+        let mut stats = BacktrackSolverStats::new();
+        let initial = if solver.solve_by_technique(&mut grid, &mut stats, &control)? {
+            SearchState::solved(grid, stats)
+        } else {
+            let assumption = backtrack::find_best_assumption(&grid);
+            SearchState::with_assumption(grid, stats, assumption)
+        };
+        Ok(Self {
             solver,
-            stack: vec![SearchState::with_assumption(grid, stats, assumption)],
-        }
+            control,
+            stack: vec![initial],
+        })
     }
-}
-
-impl Iterator for Solutions<'_> {
-    type Item = (CandidateGrid, BacktrackSolverStats);
 
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Searches for the next solution, or returns `Ok(None)` once all solutions
+    /// have been found.
+    fn next_solution(
+        &mut self,
+    ) -> Result<Option<(CandidateGrid, BacktrackSolverStats)>, SolverError> {
         while let Some(mut state) = self.stack.pop() {
             let Some((pos, remaining_digits)) = &mut state.assumption else {
-                return Some((state.grid, state.stats));
+                return Ok(Some((state.grid, state.stats)));
             };
-            let Some(digit) = remaining_digits.pop_first() else {
+            let Some(digit) = remaining_digits.first() else {
                 continue;
             };
+            if let Err(err) = self.control.check() {
+                // Keep the state, so that the search can be resumed
+                self.stack.push(state);
+                return Err(err);
+            }
+            remaining_digits.remove(digit);
             let pos = *pos;
             let mut grid = state.grid.clone();
             let mut stats = state.stats.clone();
@@ -311,26 +504,68 @@ impl Iterator for Solutions<'_> {
 
             stats.assumptions.push((pos, digit));
             grid.place(pos, digit);
-            let Ok(solved) = self.solver.solve_by_technique(&mut grid, &mut stats) else {
-                stats.backtrack_count += 1;
-                continue;
+            let solved = match self
+                .solver
+                .solve_by_technique(&mut grid, &mut stats, &self.control)
+            {
+                Ok(solved) => solved,
+                Err(SolverError::Inconsistent(_)) => {
+                    stats.backtrack_count += 1;
+                    self.control.record_backtrack()?;
+                    continue;
+                }
+                Err(err) => {
+                    // Try the digit again when the search is resumed
+                    if let Some(SearchState {
+                        assumption: Some((_, remaining_digits)),
+                        ..
+                    }) = self.stack.last_mut()
+                    {
+                        remaining_digits.insert(digit);
+                    }
+                    return Err(err);
+                }
             };
             if solved {
-                return Some((grid, stats));
+                return Ok(Some((grid, stats)));
             }
             let assumption = backtrack::find_best_assumption(&grid);
             self.stack
                 .push(SearchState::with_assumption(grid, stats, assumption));
         }
-        None
+        Ok(None)
+    }
+}
+
+impl Iterator for Solutions<'_> {
+    type Item = (CandidateGrid, BacktrackSolverStats);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.search
+            .next_solution()
+            .unwrap_or_else(|_| unreachable!("a search without limits cannot be interrupted"))
+    }
+}
+
+impl Iterator for BoundedSolutions<'_> {
+    type Item = Result<(CandidateGrid, BacktrackSolverStats), SolverError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.search.next_solution().transpose()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    };
+
     use numelace_core::{Digit, Position};
 
     use super::*;
+    use crate::Interruption;
 
     #[test]
     fn test_without_techniques_solver() {
@@ -426,6 +661,53 @@ mod tests {
         assert_ne!(grid1.to_digit_grid(), grid2.to_digit_grid());
     }
 
+    fn solved_grid() -> CandidateGrid {
+        BacktrackSolver::with_all_techniques()
+            .solve(CandidateGrid::new())
+            .unwrap()
+            .next()
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn test_count_solutions_stops_at_limit() {
+        let solver = BacktrackSolver::without_techniques();
+        assert_eq!(solver.count_solutions(&CandidateGrid::new(), 3), 3);
+        assert_eq!(solver.count_solutions(&CandidateGrid::new(), 0), 0);
+        assert_eq!(solver.count_solutions(&solved_grid(), 3), 1);
+    }
+
+    #[test]
+    fn test_uniqueness() {
+        let solver = BacktrackSolver::with_all_techniques();
+        let solution = solved_grid().to_digit_grid();
+
+        // Removing a single digit keeps the solution unique
+        let mut puzzle = solution.clone();
+        puzzle.set(Position::new(0, 0), None);
+        let puzzle = CandidateGrid::from_digit_grid(&puzzle);
+        assert_eq!(solver.uniqueness(&puzzle), Uniqueness::Unique(solution));
+        assert!(solver.is_unique(&puzzle));
+
+        assert_eq!(
+            solver.uniqueness(&CandidateGrid::new()),
+            Uniqueness::Multiple
+        );
+        assert!(!solver.is_unique(&CandidateGrid::new()));
+    }
+
+    #[test]
+    fn test_uniqueness_without_solution() {
+        let solver = BacktrackSolver::with_all_techniques();
+        let mut grid = CandidateGrid::new();
+        grid.place(Position::new(0, 0), Digit::D1);
+        grid.place(Position::new(1, 0), Digit::D1);
+
+        assert_eq!(solver.uniqueness(&grid), Uniqueness::NoSolution);
+        assert_eq!(solver.count_solutions(&grid, 2), 0);
+    }
+
     #[test]
     fn test_multiple_solutions_with_partial_grid() {
         let solver = BacktrackSolver::without_techniques();
@@ -458,6 +740,103 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_backtrack_limit_interrupts_search() {
+        let solver = BacktrackSolver::without_techniques();
+        let control = SolveControl::new();
+        let mut solutions = solver
+            .solve_with_control(CandidateGrid::new(), &control)
+            .unwrap();
+        for _ in 0..20 {
+            solutions.next().unwrap().unwrap();
+        }
+        let backtracks = control.backtracks();
+        assert!(backtracks > 0);
+
+        // The search is deterministic, so it hits a lower limit on the same path
+        let control = SolveControl::new().max_backtracks(backtracks - 1);
+        let solutions = solver
+            .solve_with_control(CandidateGrid::new(), &control)
+            .unwrap();
+        let result: Result<Vec<_>, _> = solutions.take(20).collect();
+        assert!(matches!(
+            result,
+            Err(SolverError::Interrupted(Interruption::BacktrackLimit))
+        ));
+        assert_eq!(control.backtracks(), backtracks);
+    }
+
+    #[test]
+    fn test_cancel_ends_search() {
+        let solver = BacktrackSolver::with_fundamental_techniques();
+        let control = SolveControl::new();
+        let mut solutions = solver
+            .solve_with_control(CandidateGrid::new(), &control)
+            .unwrap();
+        assert!(matches!(solutions.next(), Some(Ok(_))));
+
+        control.cancel();
+        assert!(matches!(
+            solutions.next(),
+            Some(Err(SolverError::Interrupted(Interruption::Cancelled)))
+        ));
+        assert!(matches!(
+            solver.solve_with_control(CandidateGrid::new(), &control),
+            Err(SolverError::Interrupted(Interruption::Cancelled))
+        ));
+    }
+
+    #[test]
+    fn test_interrupted_search_resumes_where_it_stopped() {
+        let solver = BacktrackSolver::with_fundamental_techniques();
+        let expected: Vec<_> = solver
+            .solve(CandidateGrid::new())
+            .unwrap()
+            .take(3)
+            .map(|(solution, _)| solution)
+            .collect();
+
+        let flag = Arc::new(AtomicBool::new(false));
+        let control = SolveControl::new().cancel_flag(Arc::clone(&flag));
+        let mut solutions = solver
+            .solve_with_control(CandidateGrid::new(), &control)
+            .unwrap();
+        let mut found = vec![];
+        while found.len() < expected.len() {
+            flag.store(true, Ordering::Relaxed);
+            assert!(matches!(solutions.next(), Some(Err(_))));
+            flag.store(false, Ordering::Relaxed);
+            found.push(solutions.next().unwrap().unwrap().0);
+        }
+        assert_eq!(found, expected);
+
+        // A clone continues from the same point
+        let mut clone = solutions.clone();
+        assert_eq!(
+            clone.next().unwrap().unwrap().0,
+            solutions.next().unwrap().unwrap().0
+        );
+    }
+
+    #[test]
+    fn test_count_solutions_with_control() {
+        let solver = BacktrackSolver::without_techniques();
+        let control = SolveControl::new().max_backtracks(0);
+        let mut grid = CandidateGrid::new();
+        grid.place(Position::new(0, 0), Digit::D1);
+        grid.place(Position::new(1, 0), Digit::D1);
+        assert!(matches!(
+            solver.count_solutions_with_control(&grid, 2, &control),
+            Ok(0)
+        ));
+
+        control.cancel();
+        assert!(matches!(
+            solver.count_solutions_with_control(&CandidateGrid::new(), 2, &control),
+            Err(SolverError::Interrupted(Interruption::Cancelled))
+        ));
+    }
+
     #[test]
     fn test_backtracking_occurs() {
         let solver = BacktrackSolver::without_techniques();
@@ -0,0 +1,284 @@
+//! Solving and rating collections of puzzles on several threads.
+//!
+//! [`BatchSolver`] spreads the puzzles of a slice or an iterator over a pool of
+//! scoped worker threads that share one [`TechniqueSolver`]. Workers pull the
+//! next puzzle as soon as they are done with one, so a few hard puzzles do not
+//! hold up the rest, and the results are returned in input order.
+
+use std::{
+    borrow::Borrow,
+    num::NonZeroUsize,
+    sync::{Mutex, PoisonError},
+    thread,
+};
+
+use numelace_core::{CandidateGrid, DigitGrid};
+
+use crate::{DifficultyRating, PuzzleRater, SolverError, TechniqueSolver, TechniqueSolverStats};
+
+/// Solves or rates many puzzles in parallel with a [`TechniqueSolver`].
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::DigitGrid;
+/// use numelace_solver::{BatchSolver, TechniqueSolver};
+///
+/// let puzzles: Vec<DigitGrid> = [
+///     "...36.94..9....526.467..1..5..2.98719318.......75...94.18.2.65.67..51.3...9..3.12",
+///     "18536294779.148.26.4679518.5.4239871.31..42658..51.3.4..8.2.65967.9..438.59683.12",
+/// ]
+/// .iter()
+/// .map(|s| s.parse())
+/// .collect::<Result<_, _>>()?;
+///
+/// let solver = TechniqueSolver::with_all_techniques();
+/// let batch = BatchSolver::new(&solver).threads(2);
+///
+/// // Results are in input order, whichever thread handled each puzzle
+/// for result in batch.rate(&puzzles) {
+///     let rated = result?;
+///     println!("{} ({} steps)", rated.rating.tier(), rated.stats.total_steps());
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BatchSolver<'a> {
+    solver: &'a TechniqueSolver,
+    threads: usize,
+}
+
+impl<'a> BatchSolver<'a> {
+    /// Creates a batch solver that solves puzzles with `solver`.
+    ///
+    /// It uses one thread per available CPU, see
+    /// [`std::thread::available_parallelism`].
+    #[must_use]
+    pub fn new(solver: &'a TechniqueSolver) -> Self {
+        let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        Self { solver, threads }
+    }
+
+    /// Sets the number of worker threads, at least one.
+    ///
+    /// With a single thread, puzzles are handled on the calling thread.
+    #[must_use]
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Solves each puzzle with techniques only, until it is solved or stuck.
+    ///
+    /// Returns one result per puzzle, in input order.
+    ///
+    /// # Errors
+    ///
+    /// A puzzle's result is [`SolverError::Inconsistent`] if the puzzle is or
+    /// becomes inconsistent. It does not affect the other puzzles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator of puzzles panics.
+    pub fn solve<I>(&self, puzzles: I) -> Vec<Result<SolvedPuzzle, SolverError>>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<DigitGrid>,
+        I::IntoIter: Send,
+    {
+        self.run(puzzles, |puzzle| {
+            let mut grid = CandidateGrid::from_digit_grid(puzzle);
+            let (solved, stats) = self.solver.solve(&mut grid)?;
+            Ok(SolvedPuzzle {
+                grid,
+                solved,
+                stats,
+            })
+        })
+    }
+
+    /// Rates each puzzle with a [`PuzzleRater`].
+    ///
+    /// Returns one result per puzzle, in input order.
+    ///
+    /// # Errors
+    ///
+    /// A puzzle's result is [`SolverError::Inconsistent`] if the puzzle is or
+    /// becomes inconsistent. It does not affect the other puzzles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator of puzzles panics.
+    pub fn rate<I>(&self, puzzles: I) -> Vec<Result<RatedPuzzle, SolverError>>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<DigitGrid>,
+        I::IntoIter: Send,
+    {
+        let rater = PuzzleRater::new(self.solver);
+        self.run(puzzles, |puzzle| {
+            let mut stats = TechniqueSolverStats::new();
+            let rating =
+                rater.rate_with_stats(&CandidateGrid::from_digit_grid(puzzle), &mut stats)?;
+            Ok(RatedPuzzle { rating, stats })
+        })
+    }
+
+    /// Applies `f` to every puzzle on the worker threads, and returns the
+    /// results in input order.
+    fn run<I, T, F>(&self, puzzles: I, f: F) -> Vec<T>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<DigitGrid>,
+        I::IntoIter: Send,
+        T: Send,
+        F: Fn(&DigitGrid) -> T + Sync,
+    {
+        if self.threads == 1 {
+            // Spawning threads is not supported on every target, such as the web
+            return puzzles
+                .into_iter()
+                .map(|puzzle| f(puzzle.borrow()))
+                .collect();
+        }
+        let puzzles = Mutex::new(puzzles.into_iter().enumerate());
+        let mut results: Vec<(usize, T)> = thread::scope(|scope| {
+            let workers: Vec<_> = (0..self.threads)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut results = vec![];
+                        loop {
+                            // Release the lock before working on the puzzle
+                            let next = puzzles
+                                .lock()
+                                .unwrap_or_else(PoisonError::into_inner)
+                                .next();
+                            let Some((index, puzzle)) = next else {
+                                break;
+                            };
+                            results.push((index, f(puzzle.borrow())));
+                        }
+                        results
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| {
+                    worker
+                        .join()
+                        .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+                })
+                .collect()
+        });
+        results.sort_unstable_by_key(|&(index, _)| index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+}
+
+/// A puzzle solved by [`BatchSolver::solve`].
+#[derive(Debug, Clone)]
+pub struct SolvedPuzzle {
+    /// The grid after the techniques were applied.
+    pub grid: CandidateGrid,
+
+    /// Whether the techniques solved the puzzle.
+    pub solved: bool,
+
+    /// The techniques applied.
+    pub stats: TechniqueSolverStats,
+}
+
+/// A puzzle rated by [`BatchSolver::rate`].
+#[derive(Debug, Clone)]
+pub struct RatedPuzzle {
+    /// The difficulty rating of the puzzle.
+    pub rating: DifficultyRating,
+
+    /// The techniques applied while rating.
+    pub stats: TechniqueSolverStats,
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{Digit, Position};
+
+    use super::*;
+
+    const PUZZLES: [&str; 3] = [
+        "...36..4..9.....2...67..1..5....987..31..............4..8...65.67.....3......3..2",
+        "...36.94..9....526.467..1..5..2.98719318.......75...94.18.2.65.67..51.3...9..3.12",
+        "18536294779.148.26.4679518.5.4239871.31..42658..51.3.4..8.2.65967.9..438.59683.12",
+    ];
+
+    fn puzzles(count: usize) -> Vec<DigitGrid> {
+        PUZZLES
+            .iter()
+            .cycle()
+            .take(count)
+            .map(|s| s.parse().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_solver_is_shareable_across_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<TechniqueSolver>();
+        assert_send_sync::<BatchSolver<'_>>();
+    }
+
+    #[test]
+    fn test_rate_matches_sequential_rating_in_order() {
+        let solver = TechniqueSolver::with_all_techniques();
+        let rater = PuzzleRater::new(&solver);
+        let puzzles = puzzles(20);
+
+        let results = BatchSolver::new(&solver).threads(4).rate(&puzzles);
+        assert_eq!(results.len(), puzzles.len());
+        for (puzzle, result) in puzzles.iter().zip(results) {
+            let rated_puzzle = result.unwrap();
+            let expected = rater.rate(&CandidateGrid::from_digit_grid(puzzle)).unwrap();
+            assert_eq!(rated_puzzle.rating, expected);
+            assert_eq!(rated_puzzle.stats.total_steps(), expected.steps());
+        }
+    }
+
+    #[test]
+    fn test_solve_from_iterator() {
+        let solver = TechniqueSolver::with_fundamental_techniques();
+        // Puzzles are parsed lazily by the workers
+        let stream = PUZZLES
+            .iter()
+            .cycle()
+            .take(7)
+            .map(|s| s.parse::<DigitGrid>().unwrap());
+        let results = BatchSolver::new(&solver).threads(3).solve(stream);
+        assert_eq!(results.len(), 7);
+        for result in results {
+            let solved_puzzle = result.unwrap();
+            assert!(solved_puzzle.solved);
+            assert!(solved_puzzle.grid.is_solved().unwrap());
+        }
+
+        assert!(
+            BatchSolver::new(&solver)
+                .solve(Vec::<DigitGrid>::new())
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_errors_stay_with_their_puzzle() {
+        let solver = TechniqueSolver::with_all_techniques();
+        let mut inconsistent = DigitGrid::new();
+        inconsistent.set(Position::new(0, 0), Some(Digit::D1));
+        inconsistent.set(Position::new(1, 0), Some(Digit::D1));
+        let mut puzzles = puzzles(4);
+        puzzles.insert(2, inconsistent);
+
+        let results = BatchSolver::new(&solver).threads(2).solve(&puzzles);
+        for (i, result) in results.iter().enumerate() {
+            assert_eq!(result.is_err(), i == 2);
+        }
+    }
+}
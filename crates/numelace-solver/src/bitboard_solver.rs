@@ -0,0 +1,555 @@
+//! Bit-parallel brute-force solver for Sudoku puzzles.
+//!
+//! This module provides a band-oriented bitboard solver modelled on `JCZSolve`
+//! and tdoku. The candidates of each digit are split into its three bands of
+//! three rows, and each band is a 27-bit word with bit `9 * row + column`: the
+//! bits of a [`DigitPositions`] cut into 27-bit slices. Within a word, a row or a
+//! box of the band is a single mask, and since the digit takes one segment (the
+//! three cells a row shares with a box) in each row and each box of the band,
+//! the candidates outside every such arrangement are removed at once. Columns
+//! link the three bands of a digit.
+//!
+//! The search alternates this propagation and singles with guesses on a cell
+//! with the fewest candidates, and copies the whole state (about 230 bytes) per
+//! guess instead of undoing changes.
+//!
+//! It applies no human-like techniques and records no statistics, which makes it
+//! the fastest way to check that a puzzle has a unique solution.
+
+use numelace_core::{
+    CandidateGrid, Digit, DigitPositions, DigitSet, containers::Array9, index::DigitSemantics,
+};
+
+use crate::{SolverError, Uniqueness, solutions};
+
+/// The cells of a band.
+const BAND: u32 = (1 << 27) - 1;
+/// The cells of the first row of a band.
+const ROW: u32 = 0x1FF;
+/// The cells of the first box of a band.
+const BOX: u32 = 0x7 | 0x7 << 9 | 0x7 << 18;
+/// The cells of the first column of a band.
+const COLUMN: u32 = 1 | 1 << 9 | 1 << 18;
+
+/// The boxes holding candidates in a row of a band, for each 9-bit row.
+const ROW_BOXES: [u8; 512] = row_boxes();
+
+/// The segments a digit can take in a band, for each set of segments holding its
+/// candidates.
+///
+/// Segment `3 * row + box` is the three cells a row of the band shares with a
+/// box. A digit takes one segment in each row and each box, so only the
+/// segments of such an arrangement within the set can hold it. An empty result
+/// is a contradiction.
+const LOCKED_SEGMENTS: [u16; 512] = locked_segments();
+
+/// The cells of each set of segments of a band.
+const SEGMENT_CELLS: [u32; 512] = segment_cells();
+
+const fn row_boxes() -> [u8; 512] {
+    let mut table = [0; 512];
+    let mut row = 0;
+    while row < 512 {
+        let mut i = 0;
+        while i < 3 {
+            if row & 0x7 << (3 * i) != 0 {
+                table[row] |= 1 << i;
+            }
+            i += 1;
+        }
+        row += 1;
+    }
+    table
+}
+
+const fn locked_segments() -> [u16; 512] {
+    // The box taken in each row
+    const ARRANGEMENTS: [[u16; 3]; 6] = [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ];
+    let mut table = [0; 512];
+    let mut segments = 0;
+    while segments < 512 {
+        let mut i = 0;
+        while i < ARRANGEMENTS.len() {
+            let [a, b, c] = ARRANGEMENTS[i];
+            let arrangement = 1 << a | 1 << (3 + b) | 1 << (6 + c);
+            if segments & arrangement == arrangement {
+                table[segments as usize] |= arrangement;
+            }
+            i += 1;
+        }
+        segments += 1;
+    }
+    table
+}
+
+const fn segment_cells() -> [u32; 512] {
+    let mut table = [0; 512];
+    let mut segments = 0;
+    while segments < 512 {
+        let mut i = 0;
+        while i < 9 {
+            if segments & 1 << i != 0 {
+                table[segments] |= 0x7 << (9 * (i / 3) + 3 * (i % 3));
+            }
+            i += 1;
+        }
+        segments += 1;
+    }
+    table
+}
+
+/// Returns the segments of a band holding candidates.
+fn segments(cells: u32) -> u16 {
+    let boxes = |row: u32| u16::from(ROW_BOXES[(row & ROW) as usize]);
+    boxes(cells) | boxes(cells >> 9) << 3 | boxes(cells >> 18) << 6
+}
+
+/// A bit-parallel brute-force solver.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::{BitboardSolver, Uniqueness};
+///
+/// let solver = BitboardSolver::new();
+/// let grid = CandidateGrid::new();
+///
+/// if let Some(solution) = solver.solve(&grid)?.next() {
+///     assert!(solution.is_solved()?);
+/// }
+/// assert_eq!(solver.uniqueness(&grid), Uniqueness::Multiple);
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BitboardSolver;
+
+impl BitboardSolver {
+    /// Creates a new bitboard solver.
+    #[must_use]
+    pub const fn new() -> Self {
+        BitboardSolver
+    }
+
+    /// Solves the puzzle and returns an iterator over all solutions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Inconsistent`] if the initial grid is inconsistent
+    /// (has cells with no candidates or contradictory placements).
+    pub fn solve(&self, grid: &CandidateGrid) -> Result<BitboardSolutions, SolverError> {
+        grid.check_consistency()?;
+        Ok(BitboardSolutions {
+            stack: vec![Board::new(grid)],
+        })
+    }
+
+    /// Counts the solutions of the puzzle, stopping once `limit` are found.
+    ///
+    /// Returns a number in `0..=limit`. An inconsistent grid has no solution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_core::CandidateGrid;
+    /// use numelace_solver::BitboardSolver;
+    ///
+    /// let solver = BitboardSolver::new();
+    /// assert_eq!(solver.count_solutions(&CandidateGrid::new(), 10), 10);
+    /// ```
+    #[must_use]
+    pub fn count_solutions(&self, grid: &CandidateGrid, limit: usize) -> usize {
+        let Ok(solutions) = self.solve(grid) else {
+            return 0;
+        };
+        let Ok(count) = solutions::count_solutions(solutions, limit);
+        count
+    }
+
+    /// Checks whether the puzzle has exactly one solution.
+    ///
+    /// The search stops as soon as a second solution is found. An inconsistent
+    /// grid has no solution.
+    #[must_use]
+    pub fn uniqueness(&self, grid: &CandidateGrid) -> Uniqueness {
+        let Ok(solutions) = self.solve(grid) else {
+            return Uniqueness::NoSolution;
+        };
+        let Ok(uniqueness) = solutions::uniqueness(solutions);
+        uniqueness
+    }
+
+    /// Returns `true` if the puzzle has exactly one solution.
+    ///
+    /// See [`uniqueness`](Self::uniqueness) to also get the solution.
+    #[must_use]
+    pub fn is_unique(&self, grid: &CandidateGrid) -> bool {
+        matches!(self.uniqueness(grid), Uniqueness::Unique(_))
+    }
+}
+
+/// An iterator over solutions to a Sudoku puzzle.
+///
+/// Created by [`BitboardSolver::solve`]. Yields each solution as a solved grid.
+#[derive(Debug, Clone)]
+pub struct BitboardSolutions {
+    /// Boards left to explore, the next one last.
+    stack: Vec<Board>,
+}
+
+impl Iterator for BitboardSolutions {
+    type Item = CandidateGrid;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(mut board) = self.stack.pop() {
+            if !board.propagate() {
+                continue;
+            }
+            let Some((band, cell, digits)) = board.guess() else {
+                return Some(board.to_candidate_grid());
+            };
+            // Push in reverse so that the smallest digit is tried first
+            for digit in digits.iter().rev() {
+                let mut next = board;
+                next.solve_cell(digit, band, cell);
+                self.stack.push(next);
+            }
+        }
+        None
+    }
+}
+
+/// The search state: the candidates of each digit in each band.
+///
+/// A solved cell keeps its digit as the only candidate, and is removed from
+/// `unsolved` once the digit has been eliminated from its peers.
+#[derive(Debug, Clone, Copy)]
+struct Board {
+    bands: Array9<[u32; 3], DigitSemantics>,
+    /// The bands as they were last reduced, to skip the unchanged ones.
+    reduced: Array9<[u32; 3], DigitSemantics>,
+    unsolved: [u32; 3],
+}
+
+impl Board {
+    fn new(grid: &CandidateGrid) -> Self {
+        let bands = Array9::from_fn(|digit| {
+            let bits = grid.digit_positions(digit).bits();
+            #[expect(clippy::cast_possible_truncation)]
+            [0, 1, 2].map(|band| (bits >> (27 * band)) as u32 & BAND)
+        });
+        Self {
+            bands,
+            // No band has bit 31 set, so every band is reduced at first
+            reduced: Array9::from_fn(|_| [u32::MAX; 3]),
+            unsolved: [BAND; 3],
+        }
+    }
+
+    /// Places `digit` at `cell` of `band` and removes it from the peers.
+    fn solve_cell(&mut self, digit: Digit, band: usize, cell: u32) {
+        let bit = 1 << cell;
+        let (row, column) = (cell / 9, cell % 9);
+        for other in Digit::ALL {
+            self.bands[other][band] &= !bit;
+        }
+        let bands = &mut self.bands[digit];
+        for cells in bands.iter_mut() {
+            *cells &= !(COLUMN << column);
+        }
+        bands[band] &= !(ROW << (9 * row) | BOX << (3 * (column / 3)));
+        bands[band] |= bit;
+        self.unsolved[band] &= !bit;
+    }
+
+    /// Applies band reductions, naked singles and hidden singles until nothing
+    /// changes.
+    ///
+    /// Returns `false` on a contradiction: a cell without candidates, or a
+    /// digit without a position in a house.
+    fn propagate(&mut self) -> bool {
+        loop {
+            if !self.update_bands() {
+                return false;
+            }
+            match self.place_naked_singles() {
+                Some(true) => continue,
+                Some(false) => {}
+                None => return false,
+            }
+            match self.place_column_singles() {
+                Some(true) => {}
+                Some(false) => return true,
+                None => return false,
+            }
+        }
+    }
+
+    /// Updates the changed bands until none changes.
+    ///
+    /// The candidates of a band that lie outside every arrangement of the
+    /// digit's segments are removed, and the digit is placed in every row and
+    /// box of the band with a single unsolved position left.
+    ///
+    /// Returns `false` if a digit has no arrangement left in a band, which
+    /// includes a row or a box of the band without candidates.
+    fn update_bands(&mut self) -> bool {
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for digit in Digit::ALL {
+                for band in 0..3 {
+                    let cells = self.bands[digit][band];
+                    if cells == self.reduced[digit][band] {
+                        continue;
+                    }
+                    changed = true;
+                    let locked = LOCKED_SEGMENTS[usize::from(segments(cells))];
+                    if locked == 0 {
+                        return false;
+                    }
+                    let cells = cells & SEGMENT_CELLS[usize::from(locked)];
+                    self.bands[digit][band] = cells;
+                    self.reduced[digit][band] = cells;
+                    if cells & self.unsolved[band] == 0 {
+                        continue;
+                    }
+                    for house in [ROW, ROW << 9, ROW << 18, BOX, BOX << 3, BOX << 6] {
+                        let cells = self.bands[digit][band] & house;
+                        if cells.is_power_of_two() && cells & self.unsolved[band] != 0 {
+                            self.solve_cell(digit, band, cells.trailing_zeros());
+                        }
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Places every unsolved cell with a single candidate.
+    ///
+    /// Returns whether any cell was placed, or `None` if a cell has no
+    /// candidates left.
+    fn place_naked_singles(&mut self) -> Option<bool> {
+        let mut placed = false;
+        for band in 0..3 {
+            let mut once = 0;
+            let mut more = 0;
+            for digit in Digit::ALL {
+                let cells = self.bands[digit][band];
+                more |= once & cells;
+                once |= cells;
+            }
+            if once != BAND {
+                return None;
+            }
+            let singles = once & !more & self.unsolved[band];
+            for digit in Digit::ALL {
+                let mut cells = singles & self.bands[digit][band];
+                while cells != 0 {
+                    let cell = cells.trailing_zeros();
+                    cells &= cells - 1;
+                    if self.bands[digit][band] & 1 << cell == 0 {
+                        // A peer took the digit in this round
+                        return None;
+                    }
+                    self.solve_cell(digit, band, cell);
+                    placed = true;
+                }
+            }
+        }
+        Some(placed)
+    }
+
+    /// Places every digit that has a single unsolved position in a column.
+    ///
+    /// Returns whether any digit was placed, or `None` if a digit has no
+    /// position left in a column.
+    fn place_column_singles(&mut self) -> Option<bool> {
+        let mut placed = false;
+        for digit in Digit::ALL {
+            let bands = self.bands[digit];
+            if (0..3).all(|band| bands[band] & self.unsolved[band] == 0) {
+                continue;
+            }
+            // Count the positions of the digit in each column, one row at a time
+            let mut once = 0;
+            let mut more = 0;
+            for cells in bands {
+                for row in [cells, cells >> 9, cells >> 18] {
+                    more |= once & row & ROW;
+                    once |= row & ROW;
+                }
+            }
+            if once != ROW {
+                return None;
+            }
+            let mut singles = once & !more;
+            while singles != 0 {
+                let column = singles.trailing_zeros();
+                singles &= singles - 1;
+                for band in 0..3 {
+                    let cells = self.bands[digit][band] & COLUMN << column;
+                    if cells & self.unsolved[band] != 0 {
+                        self.solve_cell(digit, band, cells.trailing_zeros());
+                        placed = true;
+                    }
+                }
+            }
+        }
+        Some(placed)
+    }
+
+    /// Returns an unsolved cell with the fewest candidates, as a band and a cell
+    /// of the band, with its candidates, or `None` if the board is solved.
+    fn guess(&self) -> Option<(usize, u32, DigitSet)> {
+        // The cells of each band with more than `n` candidates, for each `n`
+        let mut more_than = [[0; 3]; 10];
+        for digit in Digit::ALL {
+            for band in 0..3 {
+                let mut carry = self.bands[digit][band];
+                for cells in &mut more_than {
+                    if carry == 0 {
+                        break;
+                    }
+                    let next = cells[band] & carry;
+                    cells[band] |= carry;
+                    carry = next;
+                }
+            }
+        }
+        let (band, cells) = more_than
+            .windows(2)
+            .skip(1)
+            .flat_map(|tier| {
+                (0..3).map(move |band| (band, tier[0][band] & !tier[1][band] & self.unsolved[band]))
+            })
+            .find(|&(_, cells)| cells != 0)?;
+        let cell = cells.trailing_zeros();
+        let digits = Digit::ALL
+            .into_iter()
+            .filter(|&digit| self.bands[digit][band] & 1 << cell != 0)
+            .collect();
+        Some((band, cell, digits))
+    }
+
+    fn to_candidate_grid(self) -> CandidateGrid {
+        let mut grid = CandidateGrid::new();
+        for digit in Digit::ALL {
+            let [a, b, c] = self.bands[digit].map(u128::from);
+            let positions = DigitPositions::from_bits(a | b << 27 | c << 54);
+            grid.remove_candidate_with_mask(!positions, digit);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::Position;
+
+    use super::*;
+    use crate::{DlxSolver, testing::restrict};
+
+    #[test]
+    fn test_solutions_are_distinct_and_solved() {
+        let solutions: Vec<_> = BitboardSolver::new()
+            .solve(&CandidateGrid::new())
+            .unwrap()
+            .take(5)
+            .collect();
+        assert_eq!(solutions.len(), 5);
+        for (i, solution) in solutions.iter().enumerate() {
+            assert!(solution.is_solved().unwrap());
+            for other in &solutions[i + 1..] {
+                assert_ne!(solution, other);
+            }
+        }
+    }
+
+    #[test]
+    fn test_agrees_with_dlx_solver() {
+        let solution = DlxSolver::new()
+            .solve(&CandidateGrid::new())
+            .unwrap()
+            .next()
+            .unwrap()
+            .to_digit_grid();
+
+        for step in [2, 3, 5] {
+            let mut puzzle = solution.clone();
+            for pos in Position::ALL.into_iter().step_by(step) {
+                puzzle.set(pos, None);
+            }
+            let grid = CandidateGrid::from_digit_grid(&puzzle);
+            assert_eq!(
+                BitboardSolver::new().count_solutions(&grid, 50),
+                DlxSolver::new().count_solutions(&grid, 50)
+            );
+        }
+
+        let mut puzzle = solution.clone();
+        puzzle.set(Position::new(4, 4), None);
+        let grid = CandidateGrid::from_digit_grid(&puzzle);
+        assert_eq!(
+            BitboardSolver::new().uniqueness(&grid),
+            Uniqueness::Unique(solution)
+        );
+    }
+
+    #[test]
+    fn test_respects_eliminations() {
+        let mut grid = CandidateGrid::new();
+        grid.remove_candidate(Position::new(0, 0), Digit::D1);
+        for solution in BitboardSolver::new().solve(&grid).unwrap().take(20) {
+            assert!(
+                !solution
+                    .candidates_at(Position::new(0, 0))
+                    .contains(Digit::D1)
+            );
+        }
+    }
+
+    #[test]
+    fn test_no_solution() {
+        let solver = BitboardSolver::new();
+        let mut grid = CandidateGrid::new();
+        for x in 0..9 {
+            grid.remove_candidate(Position::new(x, 0), Digit::D1);
+        }
+        assert_eq!(solver.uniqueness(&grid), Uniqueness::NoSolution);
+
+        let mut grid = CandidateGrid::new();
+        grid.place(Position::new(0, 0), Digit::D1);
+        grid.place(Position::new(1, 0), Digit::D1);
+        assert!(solver.solve(&grid).is_err());
+        assert_eq!(solver.count_solutions(&grid, 2), 0);
+    }
+
+    #[test]
+    fn test_guess_picks_fewest_candidates() {
+        // Without a bivalue cell, the guess falls on the only cell with three
+        // candidates: row 1 of band 1
+        let mut grid = CandidateGrid::new();
+        restrict(
+            &mut grid,
+            Position::new(5, 4),
+            &[Digit::D1, Digit::D2, Digit::D3],
+        );
+        let mut board = Board::new(&grid);
+        assert!(board.propagate());
+        let (band, cell, digits) = board.guess().unwrap();
+        assert_eq!((band, cell), (1, 9 + 5));
+        assert_eq!(
+            digits,
+            DigitSet::from_iter([Digit::D1, Digit::D2, Digit::D3])
+        );
+    }
+}
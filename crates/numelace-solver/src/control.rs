@@ -0,0 +1,266 @@
+//! Limits and cancellation for long-running solves.
+//!
+//! Solving normally runs to completion, which can take long on hard or
+//! ambiguous grids. A [`SolveControl`] bounds a run by the number of technique
+//! steps, the number of backtracks and a deadline, and lets another thread (or
+//! an event handler between chunks of work) cancel it. When a limit is hit, the
+//! run stops with [`SolverError::Interrupted`].
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+use web_time::{Duration, Instant};
+
+use crate::SolverError;
+
+/// The reason a solve was stopped before completion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+pub enum Interruption {
+    /// The maximum number of technique steps was reached.
+    #[display("step limit reached")]
+    StepLimit,
+    /// The maximum number of backtracks was exceeded.
+    #[display("backtrack limit exceeded")]
+    BacktrackLimit,
+    /// The deadline has passed.
+    #[display("deadline passed")]
+    Deadline,
+    /// The run was cancelled.
+    #[display("cancelled")]
+    Cancelled,
+}
+
+/// Limits and a cooperative cancel flag for a solving run.
+///
+/// The control also counts the steps and backtracks taken so far, so one
+/// control bounds a whole run, however many solver calls it takes. Clones share
+/// the counters and the cancel flag, so they bound the same run. Create a new
+/// control for each run.
+///
+/// Accepted by [`TechniqueSolver::solve_with_control`](crate::TechniqueSolver::solve_with_control),
+/// [`BacktrackSolver::solve_with_control`](crate::BacktrackSolver::solve_with_control)
+/// and `PuzzleGenerator::generate_with_control`. Limits are checked between
+/// steps, so a run stops at most one technique application after a limit is hit.
+///
+/// Deadlines use [`web_time::Instant`], which is [`std::time::Instant`] on
+/// native targets and works on the web as well.
+///
+/// # Examples
+///
+/// ```
+/// use std::{sync::Arc, sync::atomic::AtomicBool, time::Duration};
+///
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::{BacktrackSolver, Interruption, SolveControl, SolverError};
+///
+/// let cancel = Arc::new(AtomicBool::new(false));
+/// let control = SolveControl::new()
+///     .max_backtracks(1000)
+///     .time_limit(Duration::from_secs(1))
+///     .cancel_flag(Arc::clone(&cancel));
+///
+/// let solver = BacktrackSolver::with_all_techniques();
+/// let mut solutions = solver.solve_with_control(CandidateGrid::new(), &control)?;
+/// assert!(solutions.next().transpose()?.is_some());
+///
+/// // Setting the flag, for example from another thread, stops the search
+/// control.cancel();
+/// assert!(matches!(
+///     solutions.next(),
+///     Some(Err(SolverError::Interrupted(Interruption::Cancelled)))
+/// ));
+/// # Ok::<(), SolverError>(())
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SolveControl {
+    max_steps: Option<usize>,
+    max_backtracks: Option<usize>,
+    deadline: Option<Instant>,
+    cancel_flag: Arc<AtomicBool>,
+    steps: Arc<AtomicUsize>,
+    backtracks: Arc<AtomicUsize>,
+}
+
+impl SolveControl {
+    /// Creates a control without limits.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the run to `max` technique steps.
+    #[must_use]
+    pub fn max_steps(mut self, max: usize) -> Self {
+        self.max_steps = Some(max);
+        self
+    }
+
+    /// Limits the run to `max` backtracks, that is assumptions that led to a
+    /// contradiction.
+    #[must_use]
+    pub fn max_backtracks(mut self, max: usize) -> Self {
+        self.max_backtracks = Some(max);
+        self
+    }
+
+    /// Stops the run once `deadline` has passed.
+    #[must_use]
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Stops the run once `limit` has elapsed from now.
+    #[must_use]
+    pub fn time_limit(self, limit: Duration) -> Self {
+        self.deadline(Instant::now() + limit)
+    }
+
+    /// Uses `flag` as the cancel flag, so that the run stops once it is set.
+    ///
+    /// By default, the control has a flag of its own, set by
+    /// [`cancel`](Self::cancel).
+    #[must_use]
+    pub fn cancel_flag(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.cancel_flag = flag;
+        self
+    }
+
+    /// Requests the run to stop.
+    pub fn cancel(&self) {
+        self.cancel_flag.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the run has been cancelled.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel_flag.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of technique steps taken so far.
+    #[must_use]
+    pub fn steps(&self) -> usize {
+        self.steps.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of backtracks so far.
+    #[must_use]
+    pub fn backtracks(&self) -> usize {
+        self.backtracks.load(Ordering::Relaxed)
+    }
+
+    /// Checks the cancel flag and the deadline.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Interrupted`] if the run has been cancelled or the
+    /// deadline has passed.
+    pub fn check(&self) -> Result<(), SolverError> {
+        if self.is_cancelled() {
+            return Err(Interruption::Cancelled.into());
+        }
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return Err(Interruption::Deadline.into());
+        }
+        Ok(())
+    }
+
+    /// Checks that the run may take one more technique step.
+    pub(crate) fn check_step(&self) -> Result<(), SolverError> {
+        self.check()?;
+        if self.max_steps.is_some_and(|max| self.steps() >= max) {
+            return Err(Interruption::StepLimit.into());
+        }
+        Ok(())
+    }
+
+    /// Counts a technique step.
+    pub(crate) fn record_step(&self) {
+        self.steps.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Counts a backtrack, and checks that the limit is not exceeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Interrupted`] if this backtrack exceeds the limit.
+    pub fn record_backtrack(&self) -> Result<(), SolverError> {
+        let backtracks = self.backtracks.fetch_add(1, Ordering::Relaxed) + 1;
+        if self.max_backtracks.is_some_and(|max| backtracks > max) {
+            return Err(Interruption::BacktrackLimit.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlimited_control_never_interrupts() {
+        let control = SolveControl::new();
+        for _ in 0..100 {
+            control.check_step().unwrap();
+            control.record_step();
+            control.record_backtrack().unwrap();
+        }
+        assert_eq!(control.steps(), 100);
+        assert_eq!(control.backtracks(), 100);
+    }
+
+    #[test]
+    fn test_limits() {
+        let control = SolveControl::new().max_steps(1).max_backtracks(1);
+        control.check_step().unwrap();
+        control.record_step();
+        assert!(matches!(
+            control.check_step(),
+            Err(SolverError::Interrupted(Interruption::StepLimit))
+        ));
+
+        control.record_backtrack().unwrap();
+        assert!(matches!(
+            control.record_backtrack(),
+            Err(SolverError::Interrupted(Interruption::BacktrackLimit))
+        ));
+    }
+
+    #[test]
+    fn test_deadline_and_cancel() {
+        let control = SolveControl::new().deadline(Instant::now());
+        assert!(matches!(
+            control.check(),
+            Err(SolverError::Interrupted(Interruption::Deadline))
+        ));
+
+        let flag = Arc::new(AtomicBool::new(false));
+        let control = SolveControl::new()
+            .time_limit(Duration::from_secs(3600))
+            .cancel_flag(Arc::clone(&flag));
+        control.check().unwrap();
+        flag.store(true, Ordering::Relaxed);
+        assert!(control.is_cancelled());
+        assert!(matches!(
+            control.check(),
+            Err(SolverError::Interrupted(Interruption::Cancelled))
+        ));
+    }
+
+    #[test]
+    fn test_clones_share_the_run() {
+        let control = SolveControl::new().max_steps(1);
+        let clone = control.clone();
+        clone.record_step();
+        assert_eq!(control.steps(), 1);
+        assert!(control.check_step().is_err());
+
+        control.cancel();
+        assert!(clone.is_cancelled());
+    }
+}
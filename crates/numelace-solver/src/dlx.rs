@@ -0,0 +1,253 @@
+//! Exact cover search with Dancing Links (Knuth's Algorithm X).
+//!
+//! An exact cover problem is a set of columns (constraints) and a set of rows
+//! (choices), each row covering some of the columns. A solution is a set of rows
+//! covering every column exactly once. Sudoku maps to 324 columns (each cell,
+//! and each digit in each row, column and box, is filled exactly once) and 729
+//! rows (each digit in each cell); variant constraints add columns.
+//!
+//! The matrix is stored as circular doubly linked lists in flat arrays. Covering
+//! a column unlinks it and every row that intersects it; uncovering relinks them
+//! in reverse order, which restores the matrix exactly.
+
+/// Index of the root header, which links the uncovered column headers.
+const ROOT: usize = 0;
+
+/// A sparse exact cover matrix with a resumable search.
+#[derive(Debug, Clone)]
+pub(crate) struct ExactCover {
+    left: Vec<usize>,
+    right: Vec<usize>,
+    up: Vec<usize>,
+    down: Vec<usize>,
+    /// Column header of each node.
+    column: Vec<usize>,
+    /// Row of each node (unused for headers).
+    row: Vec<usize>,
+    /// Number of rows linked in each column (indexed by header).
+    size: Vec<usize>,
+    rows: usize,
+    /// The node of the row chosen at each search level.
+    stack: Vec<usize>,
+    started: bool,
+}
+
+impl ExactCover {
+    /// Creates a matrix with `columns` columns and no rows, with room for
+    /// `nodes` row entries.
+    pub(crate) fn with_capacity(columns: usize, nodes: usize) -> Self {
+        let headers = columns + 1;
+        let links = |f: fn(usize) -> usize| {
+            let mut links = Vec::with_capacity(headers + nodes);
+            links.extend((0..headers).map(f));
+            links
+        };
+        let mut matrix = Self {
+            left: links(|i| i.wrapping_sub(1)),
+            right: links(|i| i + 1),
+            up: links(|i| i),
+            down: links(|i| i),
+            column: links(|i| i),
+            row: links(|_| usize::MAX),
+            size: vec![0; headers],
+            rows: 0,
+            stack: vec![],
+            started: false,
+        };
+        matrix.left[ROOT] = columns;
+        matrix.right[columns] = ROOT;
+        matrix
+    }
+
+    /// Adds a row covering `columns`, returning its index.
+    ///
+    /// Rows are numbered from 0 in the order they are added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a column is out of range, or if the search has started.
+    pub(crate) fn add_row(&mut self, columns: &[usize]) -> usize {
+        assert!(!self.started);
+        let row = self.rows;
+        self.rows += 1;
+        let first = self.left.len();
+        for (i, &column) in columns.iter().enumerate() {
+            let header = column + 1;
+            assert!(header < self.size.len());
+            let node = first + i;
+            self.left.push(if i == 0 { node } else { node - 1 });
+            self.right.push(first);
+            if i > 0 {
+                self.right[node - 1] = node;
+                self.left[first] = node;
+            }
+            self.up.push(self.up[header]);
+            self.down.push(header);
+            let last = self.up[header];
+            self.down[last] = node;
+            self.up[header] = node;
+            self.column.push(header);
+            self.row.push(row);
+            self.size[header] += 1;
+        }
+        row
+    }
+
+    /// Finds the next exact cover, returning the indices of its rows.
+    ///
+    /// Successive calls enumerate every solution once, then return `None`.
+    pub(crate) fn next_solution(&mut self) -> Option<Vec<usize>> {
+        let mut backtrack = self.started;
+        self.started = true;
+        loop {
+            if backtrack {
+                let node = self.stack.pop()?;
+                self.uncover_row(node);
+                let header = self.column[node];
+                let next = self.down[node];
+                if next == header {
+                    self.uncover(header);
+                    continue;
+                }
+                self.cover_row(next);
+                self.stack.push(next);
+                backtrack = false;
+            }
+            if self.right[ROOT] == ROOT {
+                return Some(self.stack.iter().map(|&node| self.row[node]).collect());
+            }
+            let header = self.choose_column();
+            if self.size[header] == 0 {
+                backtrack = true;
+                continue;
+            }
+            self.cover(header);
+            let node = self.down[header];
+            self.cover_row(node);
+            self.stack.push(node);
+        }
+    }
+
+    /// Returns the uncovered column with the fewest rows.
+    ///
+    /// A column with a single row is forced, so the scan stops there.
+    fn choose_column(&self) -> usize {
+        let mut best = self.right[ROOT];
+        let mut header = self.right[best];
+        while header != ROOT && self.size[best] > 1 {
+            if self.size[header] < self.size[best] {
+                best = header;
+            }
+            header = self.right[header];
+        }
+        best
+    }
+
+    /// Covers the other columns of the row of `node`.
+    fn cover_row(&mut self, node: usize) {
+        let mut j = self.right[node];
+        while j != node {
+            self.cover(self.column[j]);
+            j = self.right[j];
+        }
+    }
+
+    /// Uncovers the other columns of the row of `node`, undoing [`cover_row`](Self::cover_row).
+    fn uncover_row(&mut self, node: usize) {
+        let mut j = self.left[node];
+        while j != node {
+            self.uncover(self.column[j]);
+            j = self.left[j];
+        }
+    }
+
+    /// Unlinks the column `header` and every row intersecting it.
+    fn cover(&mut self, header: usize) {
+        let (left, right) = (self.left[header], self.right[header]);
+        self.right[left] = right;
+        self.left[right] = left;
+        let mut i = self.down[header];
+        while i != header {
+            let mut j = self.right[i];
+            while j != i {
+                let (up, down) = (self.up[j], self.down[j]);
+                self.down[up] = down;
+                self.up[down] = up;
+                self.size[self.column[j]] -= 1;
+                j = self.right[j];
+            }
+            i = self.down[i];
+        }
+    }
+
+    /// Relinks the column `header` and its rows, undoing [`cover`](Self::cover).
+    fn uncover(&mut self, header: usize) {
+        let mut i = self.up[header];
+        while i != header {
+            let mut j = self.left[i];
+            while j != i {
+                let (up, down) = (self.up[j], self.down[j]);
+                self.down[up] = j;
+                self.up[down] = j;
+                self.size[self.column[j]] += 1;
+                j = self.left[j];
+            }
+            i = self.up[i];
+        }
+        let (left, right) = (self.left[header], self.right[header]);
+        self.right[left] = header;
+        self.left[right] = header;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solutions(mut matrix: ExactCover) -> Vec<Vec<usize>> {
+        let mut solutions = vec![];
+        while let Some(mut rows) = matrix.next_solution() {
+            rows.sort_unstable();
+            solutions.push(rows);
+        }
+        solutions
+    }
+
+    #[test]
+    fn test_knuth_example() {
+        // The example from Knuth's "Dancing Links" paper, with a single solution
+        let mut matrix = ExactCover::with_capacity(7, 0);
+        matrix.add_row(&[2, 4, 5]);
+        matrix.add_row(&[0, 3, 6]);
+        matrix.add_row(&[1, 2, 5]);
+        matrix.add_row(&[0, 3]);
+        matrix.add_row(&[1, 6]);
+        matrix.add_row(&[3, 4, 6]);
+        assert_eq!(solutions(matrix), [vec![0, 3, 4]]);
+    }
+
+    #[test]
+    fn test_enumerates_every_solution() {
+        let mut matrix = ExactCover::with_capacity(2, 0);
+        matrix.add_row(&[0]);
+        matrix.add_row(&[1]);
+        matrix.add_row(&[0, 1]);
+        let mut found = solutions(matrix);
+        found.sort();
+        assert_eq!(found, [vec![0, 1], vec![2]]);
+    }
+
+    #[test]
+    fn test_uncoverable_column_has_no_solution() {
+        let mut matrix = ExactCover::with_capacity(2, 0);
+        matrix.add_row(&[0]);
+        assert_eq!(solutions(matrix), Vec::<Vec<usize>>::new());
+    }
+
+    #[test]
+    fn test_empty_matrix_has_one_empty_solution() {
+        let mut matrix = ExactCover::with_capacity(0, 0);
+        assert_eq!(matrix.next_solution(), Some(vec![]));
+        assert_eq!(matrix.next_solution(), None);
+    }
+}
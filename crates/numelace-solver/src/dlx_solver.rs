@@ -0,0 +1,255 @@
+//! Exact cover solver for Sudoku puzzles.
+//!
+//! This module provides a solver that maps the puzzle onto an exact cover
+//! problem and searches it with Dancing Links. Unlike
+//! [`BacktrackSolver`](crate::BacktrackSolver), it applies no human-like
+//! techniques, which makes it fast for enumerating and counting solutions.
+
+use numelace_core::{CandidateGrid, Digit, DigitGrid, Position};
+
+use crate::{SolverError, Uniqueness, dlx::ExactCover, solutions};
+
+/// Number of exact cover columns: each cell is filled, and each digit appears in
+/// each row, column and box, exactly once.
+const COLUMNS: usize = 4 * 81;
+
+/// An exact cover (Dancing Links) solver.
+///
+/// Each remaining candidate of the grid is a row of the exact cover matrix,
+/// covering four constraints: its cell, and its digit in its row, column and
+/// box. A solution picks exactly one row per constraint. Candidates already
+/// removed from the grid are not part of the matrix, so eliminations are
+/// respected.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::DlxSolver;
+///
+/// let solver = DlxSolver::new();
+/// let grid = CandidateGrid::new();
+///
+/// if let Some(solution) = solver.solve(&grid)?.next() {
+///     assert!(solution.is_solved()?);
+/// }
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DlxSolver;
+
+impl DlxSolver {
+    /// Creates a new exact cover solver.
+    #[must_use]
+    pub const fn new() -> Self {
+        DlxSolver
+    }
+
+    /// Solves the puzzle and returns an iterator over all solutions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Inconsistent`] if the initial grid is inconsistent
+    /// (has cells with no candidates or contradictory placements).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_core::CandidateGrid;
+    /// use numelace_solver::DlxSolver;
+    ///
+    /// let solver = DlxSolver::new();
+    /// let solutions: Vec<_> = solver.solve(&CandidateGrid::new())?.take(3).collect();
+    /// assert_eq!(solutions.len(), 3);
+    /// # Ok::<(), numelace_solver::SolverError>(())
+    /// ```
+    pub fn solve(&self, grid: &CandidateGrid) -> Result<DlxSolutions, SolverError> {
+        grid.check_consistency()?;
+        let rows = Position::ALL
+            .into_iter()
+            .map(|pos| grid.candidates_at(pos).len())
+            .sum::<usize>();
+        let mut matrix = ExactCover::with_capacity(COLUMNS, 4 * rows);
+        let mut candidates = Vec::with_capacity(rows);
+        for pos in Position::ALL {
+            for digit in grid.candidates_at(pos) {
+                matrix.add_row(&constraints(pos, digit));
+                candidates.push((pos, digit));
+            }
+        }
+        Ok(DlxSolutions { matrix, candidates })
+    }
+
+    /// Counts the solutions of the puzzle, stopping once `limit` are found.
+    ///
+    /// Returns a number in `0..=limit`. An inconsistent grid has no solution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_core::CandidateGrid;
+    /// use numelace_solver::DlxSolver;
+    ///
+    /// let solver = DlxSolver::new();
+    /// assert_eq!(solver.count_solutions(&CandidateGrid::new(), 10), 10);
+    /// ```
+    #[must_use]
+    pub fn count_solutions(&self, grid: &CandidateGrid, limit: usize) -> usize {
+        let Ok(solutions) = self.solve(grid) else {
+            return 0;
+        };
+        let Ok(count) = solutions::count_solutions(solutions, limit);
+        count
+    }
+
+    /// Checks whether the puzzle has exactly one solution.
+    ///
+    /// The search stops as soon as a second solution is found. An inconsistent
+    /// grid has no solution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_core::CandidateGrid;
+    /// use numelace_solver::{DlxSolver, Uniqueness};
+    ///
+    /// let solver = DlxSolver::new();
+    /// assert_eq!(solver.uniqueness(&CandidateGrid::new()), Uniqueness::Multiple);
+    /// ```
+    #[must_use]
+    pub fn uniqueness(&self, grid: &CandidateGrid) -> Uniqueness {
+        let Ok(solutions) = self.solve(grid) else {
+            return Uniqueness::NoSolution;
+        };
+        let Ok(uniqueness) = solutions::uniqueness(solutions);
+        uniqueness
+    }
+
+    /// Returns `true` if the puzzle has exactly one solution.
+    ///
+    /// See [`uniqueness`](Self::uniqueness) to also get the solution.
+    #[must_use]
+    pub fn is_unique(&self, grid: &CandidateGrid) -> bool {
+        matches!(self.uniqueness(grid), Uniqueness::Unique(_))
+    }
+}
+
+/// Returns the exact cover columns of `digit` at `pos`.
+fn constraints(pos: Position, digit: Digit) -> [usize; 4] {
+    let (x, y, b) = (
+        usize::from(pos.x()),
+        usize::from(pos.y()),
+        usize::from(pos.box_index()),
+    );
+    let d = usize::from(digit.value() - 1);
+    [y * 9 + x, 81 + y * 9 + d, 162 + x * 9 + d, 243 + b * 9 + d]
+}
+
+/// An iterator over solutions to a Sudoku puzzle.
+///
+/// Created by [`DlxSolver::solve`]. Yields each solution as a solved grid.
+#[derive(Debug, Clone)]
+pub struct DlxSolutions {
+    matrix: ExactCover,
+    /// The candidate of each matrix row.
+    candidates: Vec<(Position, Digit)>,
+}
+
+impl Iterator for DlxSolutions {
+    type Item = CandidateGrid;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rows = self.matrix.next_solution()?;
+        let mut solution = DigitGrid::new();
+        for row in rows {
+            let (pos, digit) = self.candidates[row];
+            solution.set(pos, Some(digit));
+        }
+        Some(CandidateGrid::from_digit_grid(&solution))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BacktrackSolver;
+
+    #[test]
+    fn test_solutions_are_distinct_and_solved() {
+        let solver = DlxSolver::new();
+        let solutions: Vec<_> = solver
+            .solve(&CandidateGrid::new())
+            .unwrap()
+            .take(5)
+            .collect();
+        for (i, solution) in solutions.iter().enumerate() {
+            assert!(solution.is_solved().unwrap());
+            for other in &solutions[i + 1..] {
+                assert_ne!(solution, other);
+            }
+        }
+    }
+
+    #[test]
+    fn test_respects_placements_and_eliminations() {
+        let solver = DlxSolver::new();
+        let mut grid = CandidateGrid::new();
+        grid.place(Position::new(0, 0), Digit::D1);
+        grid.remove_candidate(Position::new(1, 0), Digit::D2);
+
+        for solution in solver.solve(&grid).unwrap().take(20) {
+            let solution = solution.to_digit_grid();
+            assert_eq!(solution[Position::new(0, 0)], Some(Digit::D1));
+            assert_ne!(solution[Position::new(1, 0)], Some(Digit::D2));
+        }
+    }
+
+    #[test]
+    fn test_agrees_with_backtrack_solver() {
+        let solution = BacktrackSolver::with_all_techniques()
+            .solve(CandidateGrid::new())
+            .unwrap()
+            .next()
+            .unwrap()
+            .0
+            .to_digit_grid();
+
+        // Removing every other digit may allow several solutions
+        let mut puzzle = solution.clone();
+        for pos in Position::ALL.into_iter().step_by(2) {
+            puzzle.set(pos, None);
+        }
+        let grid = CandidateGrid::from_digit_grid(&puzzle);
+        let backtrack = BacktrackSolver::without_techniques().count_solutions(&grid, 100);
+        assert_eq!(DlxSolver::new().count_solutions(&grid, 100), backtrack);
+
+        let mut puzzle = solution.clone();
+        puzzle.set(Position::new(4, 4), None);
+        let grid = CandidateGrid::from_digit_grid(&puzzle);
+        assert_eq!(
+            DlxSolver::new().uniqueness(&grid),
+            Uniqueness::Unique(solution)
+        );
+    }
+
+    #[test]
+    fn test_no_solution() {
+        let solver = DlxSolver::new();
+        let mut grid = CandidateGrid::new();
+        for digit in Digit::ALL {
+            if digit != Digit::D1 {
+                grid.remove_candidate(Position::new(0, 0), digit);
+                grid.remove_candidate(Position::new(1, 0), digit);
+            }
+        }
+        assert!(solver.solve(&grid).is_err());
+        assert_eq!(solver.uniqueness(&grid), Uniqueness::NoSolution);
+
+        // Row 0 has no room left for D1
+        let mut grid = CandidateGrid::new();
+        for x in 0..9 {
+            grid.remove_candidate(Position::new(x, 0), Digit::D1);
+        }
+        assert_eq!(solver.count_solutions(&grid, 2), 0);
+    }
+}
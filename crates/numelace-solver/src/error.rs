@@ -1,5 +1,7 @@
 use numelace_core::ConsistencyError;
 
+use crate::Interruption;
+
 /// Errors that can occur during solving.
 #[derive(Debug, derive_more::Display, derive_more::Error, derive_more::From)]
 pub enum SolverError {
@@ -7,36 +9,71 @@ pub enum SolverError {
     ///
     /// This error wraps [`ConsistencyError`] and indicates that the puzzle is
     /// invalid or unsolvable. See [`ConsistencyError`] for the specific types
-    /// of inconsistencies that can occur.
+    /// of inconsistencies that can occur, and the cell, house and digit at
+    /// which each was found.
     #[display("inconsistency detected: {_0}")]
     Inconsistent(ConsistencyError),
+    /// Solving was stopped by a [`SolveControl`](crate::SolveControl) limit or
+    /// by cancellation.
+    ///
+    /// Unlike [`Inconsistent`](Self::Inconsistent), this says nothing about the
+    /// puzzle itself.
+    #[display("solving interrupted: {_0}")]
+    Interrupted(Interruption),
 }
 
 #[cfg(test)]
 mod tests {
+    use numelace_core::{Digit, House, Position};
+
     use super::*;
 
     #[test]
     fn test_consistency_error_converts_to_solver_error() {
-        let consistency_error = ConsistencyError::NoCandidates;
+        let pos = Position::new(2, 3);
+        let consistency_error = ConsistencyError::NoCandidates { pos };
         let solver_error: SolverError = consistency_error.into();
         assert!(matches!(
             solver_error,
-            SolverError::Inconsistent(ConsistencyError::NoCandidates)
+            SolverError::Inconsistent(ConsistencyError::NoCandidates { pos: p }) if p == pos
         ));
+        assert_eq!(
+            solver_error.to_string(),
+            "inconsistency detected: cell r4c3 has no candidates"
+        );
     }
 
     #[test]
     fn test_consistency_error_conversion_with_question_mark() {
         fn check() -> Result<(), SolverError> {
-            Err(ConsistencyError::DuplicatedDecidedDigits)?
+            Err(ConsistencyError::DuplicatedDecidedDigits {
+                digit: Digit::D1,
+                house: House::Row { y: 0 },
+                positions: [Position::new(0, 0), Position::new(5, 0)],
+            })?
         }
 
         let result = check();
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
-            SolverError::Inconsistent(ConsistencyError::DuplicatedDecidedDigits)
+            SolverError::Inconsistent(ConsistencyError::DuplicatedDecidedDigits {
+                digit: Digit::D1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_interruption_converts_to_solver_error() {
+        let solver_error: SolverError = Interruption::Deadline.into();
+        assert!(matches!(
+            solver_error,
+            SolverError::Interrupted(Interruption::Deadline)
         ));
+        assert_eq!(
+            solver_error.to_string(),
+            "solving interrupted: deadline passed"
+        );
     }
 }
@@ -7,6 +7,13 @@
 //! - [`TechniqueSolver`]: Applies human-like solving techniques only
 //! - [`BacktrackSolver`]: Combines techniques with backtracking for complete solving
 //!
+//! [`DlxSolver`] is an alternative complete solver that searches the puzzle as an
+//! exact cover problem with Dancing Links. It applies no techniques, which makes
+//! it a fast way to enumerate or count solutions. [`BitboardSolver`] searches the
+//! same way as a plain backtracking solver, but keeps each digit's candidates in
+//! one bitboard and only propagates singles; it is the fastest way to check that
+//! a puzzle has a unique solution.
+//!
 //! The solver uses a two-layer architecture that separates technique-only solving from
 //! backtracking, allowing for difficulty evaluation and flexible solving strategies.
 //!
@@ -19,7 +26,9 @@
 //! 1. **[`TechniqueSolver`]**: Applies only human-like techniques, no backtracking
 //!    - Returns when stuck (no more progress possible)
 //!    - Useful for evaluating puzzle difficulty
-//!    - Can be used for step-by-step solving with user hints
+//!    - Can be used for step-by-step solving with user hints (see [`TechniqueSolver::hint`])
+//!    - Can record the path it took as a replayable [`SolveTrace`]
+//!      (see [`TechniqueSolver::solve_with_trace`])
 //!
 //! 2. **[`BacktrackSolver`]**: Uses [`TechniqueSolver`] first, then backtracks when stuck
 //!    - Guarantees finding all solutions if they exist
@@ -115,8 +124,8 @@
 //! let mut stats = TechniqueSolverStats::new();
 //!
 //! // Apply one technique at a time
-//! while solver.step(&mut grid, &mut stats)? {
-//!     println!("Progress! Total steps: {}", stats.total_steps());
+//! while let Some(step) = solver.step(&mut grid, &mut stats)? {
+//!     println!("Applied {}! Total steps: {}", step.technique(), stats.total_steps());
 //!
 //!     if grid.is_solved()? {
 //!         println!("Puzzle solved!");
@@ -130,19 +139,17 @@
 //!
 //! ```
 //! use numelace_core::CandidateGrid;
-//! use numelace_solver::BacktrackSolver;
+//! use numelace_solver::{BacktrackSolver, Uniqueness};
 //!
 //! let solver = BacktrackSolver::with_all_techniques();
 //! let grid = CandidateGrid::new();
 //!
 //! // Check if puzzle has a unique solution
-//! let solutions: Vec<_> = solver.solve(grid)?.take(2).collect();
-//! match solutions.len() {
-//!     0 => println!("No solution"),
-//!     1 => println!("Unique solution - valid puzzle"),
-//!     _ => println!("Multiple solutions - invalid puzzle"),
+//! match solver.uniqueness(&grid) {
+//!     Uniqueness::NoSolution => println!("No solution"),
+//!     Uniqueness::Unique(_) => println!("Unique solution - valid puzzle"),
+//!     Uniqueness::Multiple => println!("Multiple solutions - invalid puzzle"),
 //! }
-//! # Ok::<(), numelace_solver::SolverError>(())
 //! ```
 //!
 //! ## Custom Technique Selection
@@ -173,6 +180,83 @@
 //!
 //! - [`NakedSingle`](technique::NakedSingle): A cell with only one candidate
 //! - [`HiddenSingle`](technique::HiddenSingle): A digit that can only go in one cell in a house
+//! - [`LockedCandidates`](technique::LockedCandidates): A digit confined to a box/line intersection
+//!   (pointing and claiming)
+//! - [`NakedSubset`](technique::NakedSubset): N cells in a house limited to the same N digits
+//!   (pairs, triples, quads)
+//! - [`HiddenSubset`](technique::HiddenSubset): N digits in a house confined to the same N cells
+//!   (pairs, triples, quads)
+//! - [`BasicFish`](technique::BasicFish): A digit in N lines confined to N cross lines
+//!   (X-Wing, Swordfish, Jellyfish)
+//! - [`Skyscraper`](technique::Skyscraper): Two parallel conjugate pairs of a digit sharing one end line
+//! - [`TwoStringKite`](technique::TwoStringKite): A row and a column conjugate pair joined in a box
+//! - [`EmptyRectangle`](technique::EmptyRectangle): A box cross of a digit combined with a conjugate pair
+//! - [`SimpleColoring`](technique::SimpleColoring): Two-coloring of a digit's conjugate pairs
+//!   (color wrap and color trap)
+//! - [`XyWing`](technique::XyWing): A bivalue pivot with two bivalue pincers
+//! - [`XyzWing`](technique::XyzWing): A trivalue pivot with two bivalue pincers
+//! - [`WWing`](technique::WWing): Two identical bivalue cells connected by a strong link
+//! - [`FinnedFish`](technique::FinnedFish): A basic fish with extra candidates confined to one box
+//!   (finned and sashimi variants)
+//! - [`MultiColoring`](technique::MultiColoring): Two coloring clusters of a digit combined
+//! - [`SueDeCoq`](technique::SueDeCoq): Box/line intersection candidates split between the line
+//!   and the box
+//! - [`XChain`](technique::XChain): An alternating chain of links of a single digit
+//! - [`XyChain`](technique::XyChain): An alternating chain of bivalue cells
+//! - [`AlsXz`](technique::AlsXz): Two Almost Locked Sets joined by a restricted common candidate
+//! - [`AlsXyWing`](technique::AlsXyWing): Two Almost Locked Sets joined through a pivot set
+//! - [`Aic`](technique::Aic): An alternating inference chain over any candidates
+//!   (including discontinuous loops)
+//!
+//! The following uniqueness techniques assume the puzzle has a unique solution and are only
+//! applied when enabled with [`TechniqueSolver::assume_unique_solution`]:
+//!
+//! - [`UniqueRectangle`](technique::UniqueRectangle): Avoids a rectangle of two interchangeable
+//!   digits (types 1-4 and hidden)
+//! - [`BugPlusOne`](technique::BugPlusOne): Resolves an all-bivalue grid with one trivalue cell
+//!
+//! As a last resort, the following techniques assume a candidate and follow its consequences
+//! with an inner [`TechniqueSolver`] for a bounded number of steps. Unlike
+//! [`BacktrackSolver`], they only keep deductions that hold whatever the outcome. They are
+//! costly, so they are provided separately by
+//! [`technique::forcing_techniques`]:
+//!
+//! - [`Nishio`](technique::Nishio): Removes a candidate whose assumption leads to a contradiction
+//! - [`CellForcingChain`](technique::CellForcingChain): Keeps the common consequences of every
+//!   candidate of a cell
+//! - [`DigitForcingChain`](technique::DigitForcingChain): Keeps the common consequences of every
+//!   position of a digit in a house
+//!
+//! ## Difficulty Rating
+//!
+//! [`PuzzleRater`] solves a puzzle step by step and turns the path into a
+//! [`DifficultyRating`]: a comparable score and a [`DifficultyTier`], driven by
+//! the weight of the hardest technique needed (see [`technique_weight`]), how
+//! often it is needed and how early. Include [`technique::forcing_techniques`]
+//! in the solver to rate the hardest puzzles without falling back to guessing.
+//!
+//! ```
+//! use numelace_core::CandidateGrid;
+//! use numelace_solver::{PuzzleRater, TechniqueSolver};
+//!
+//! let solver = TechniqueSolver::with_all_techniques();
+//! let grid = CandidateGrid::new();
+//!
+//! let rating = PuzzleRater::new(&solver).rate(&grid)?;
+//! println!("{} ({})", rating.tier(), rating.score());
+//! # Ok::<(), numelace_solver::SolverError>(())
+//! ```
+//!
+//! To rate a whole collection, [`BatchSolver`] shares one [`TechniqueSolver`]
+//! between scoped worker threads and returns the ratings in input order.
+//!
+//! For puzzles the techniques cannot solve, [`BackdoorAnalyzer`] finds the
+//! fewest solution digits to place before they can, and measures the depth and
+//! breadth of the guess tree.
+//!
+//! [`NotesChecker`] compares a player's fills and notes with the solution of the
+//! givens and the candidates a solver leaves, reporting wrong fills, dead notes
+//! and solution digits missing from the notes.
 //!
 //! ## Adding New Techniques
 //!
@@ -182,7 +266,7 @@
 //!
 //! ```
 //! use numelace_core::CandidateGrid;
-//! use numelace_solver::{SolverError, technique::Technique};
+//! use numelace_solver::{SolverError, Step, technique::Technique};
 //!
 //! #[derive(Debug, Clone)]
 //! struct MyTechnique;
@@ -196,10 +280,10 @@
 //!         Box::new(self.clone())
 //!     }
 //!
-//!     fn apply(&self, grid: &mut CandidateGrid) -> Result<bool, SolverError> {
+//!     fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
 //!         // Apply your technique logic here
-//!         // Return Ok(true) if progress was made
-//!         Ok(false)
+//!         // Return Ok(Some(step)) describing the changes if progress was made
+//!         Ok(None)
 //!     }
 //! }
 //! ```
@@ -223,14 +307,32 @@
 //! - Contradictory placements
 //!
 //! This typically indicates the input puzzle is invalid or unsolvable.
+//!
+//! Runs bounded by a [`SolveControl`] (step and backtrack limits, a deadline, or
+//! a cancel flag) stop with [`SolverError::Interrupted`] instead when a limit is
+//! hit.
 
-pub use self::{backtrack_solver::*, error::*, technique_solver::*};
+pub use self::{
+    analysis::*, backtrack_solver::*, batch::*, bitboard_solver::*, control::*, dlx_solver::*,
+    error::*, notes::*, rating::*, step::*, technique_solver::*, trace::*,
+};
 
+mod analysis;
 pub mod backtrack;
 mod backtrack_solver;
+mod batch;
+mod bitboard_solver;
+mod control;
+mod dlx;
+mod dlx_solver;
 mod error;
+mod notes;
+mod rating;
+mod solutions;
+mod step;
 pub mod technique;
 mod technique_solver;
+mod trace;
 
 #[cfg(test)]
 mod testing;
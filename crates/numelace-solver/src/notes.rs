@@ -0,0 +1,323 @@
+//! Checking a player's notes against the candidates that are really left.
+
+use numelace_core::{CandidateGrid, DigitGrid, Position};
+
+use crate::{BitboardSolver, Candidate, SolverError, TechniqueSolver, Uniqueness};
+
+/// Checks a player's notes with a [`TechniqueSolver`].
+///
+/// The solution is computed from the givens alone, so that a wrong fill of the
+/// player cannot hide it. A filled digit is *wrong* if it differs from the
+/// solution, and a digit is *wrongly eliminated* if it is the solution digit of
+/// a cell but missing from the notes of that cell.
+///
+/// The real candidates are those left once the solver has propagated the
+/// givens and the fills that are not wrong. A note is *dead* if the solver
+/// eliminated it. The more techniques the solver has, the more notes it finds
+/// dead: a solver without techniques only eliminates the digits seen in peer
+/// cells.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::{CandidateGrid, Digit, DigitGrid, Position};
+/// use numelace_solver::{Candidate, NotesChecker, TechniqueSolver};
+///
+/// let givens: DigitGrid =
+///     "...36..4..9.....2...67..1..5....987..31..............4..8...65.67.....3......3..2"
+///         .parse()?;
+/// let solver = TechniqueSolver::new(vec![]);
+///
+/// // The player noted 2 and 3 in r1c1, and nothing elsewhere
+/// let r1c1 = Position::new(0, 0);
+/// let mut notes = CandidateGrid::new();
+/// for pos in Position::ALL {
+///     for digit in Digit::ALL {
+///         if pos != r1c1 || !matches!(digit, Digit::D2 | Digit::D3) {
+///             notes.remove_candidate(pos, digit);
+///         }
+///     }
+/// }
+///
+/// let check = NotesChecker::new(&solver).check(&givens, &DigitGrid::new(), &notes)?;
+/// // 1 is the solution digit of r1c1
+/// assert_eq!(check.wrongly_eliminated(), [Candidate::new(r1c1, Digit::D1)]);
+/// // 3 is given in r1c4
+/// assert_eq!(check.dead(), [Candidate::new(r1c1, Digit::D3)]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct NotesChecker<'a> {
+    solver: &'a TechniqueSolver,
+}
+
+impl<'a> NotesChecker<'a> {
+    /// Creates a new checker that propagates puzzles with `solver`.
+    #[must_use]
+    pub fn new(solver: &'a TechniqueSolver) -> Self {
+        Self { solver }
+    }
+
+    /// Compares the fills and notes of a player with the puzzle of `givens`.
+    ///
+    /// `fills` holds the digits filled by the player, and `notes` the noted
+    /// digits of the other cells. Cells with a digit in `givens` or `fills` and
+    /// cells without candidates in `notes` (no notes taken) are not checked for
+    /// notes.
+    ///
+    /// Wrong fills and wrongly eliminated digits are only reported if `givens`
+    /// have a single solution. Otherwise no fill can be told wrong, and fills
+    /// are not propagated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Inconsistent`] if `givens` contradict each other,
+    /// or the solver finds a contradiction.
+    pub fn check(
+        &self,
+        givens: &DigitGrid,
+        fills: &DigitGrid,
+        notes: &CandidateGrid,
+    ) -> Result<NotesCheck, SolverError> {
+        let mut real = CandidateGrid::from_digit_grid(givens);
+        real.check_consistency()?;
+        let solution = match BitboardSolver::new().uniqueness(&real) {
+            Uniqueness::Unique(solution) => Some(solution),
+            Uniqueness::NoSolution | Uniqueness::Multiple => None,
+        };
+
+        let mut check = NotesCheck::default();
+        for pos in Position::ALL {
+            // Givens and fills that agree with the solution are known digits
+            let known = match (givens[pos], fills[pos], &solution) {
+                (Some(digit), _, _) => digit,
+                (None, Some(digit), Some(solution)) if solution[pos] == Some(digit) => digit,
+                (None, Some(digit), Some(_)) => {
+                    check.wrong_fills.push(Candidate::new(pos, digit));
+                    continue;
+                }
+                (None, _, _) => continue,
+            };
+            real.place(pos, known);
+            real.remove_candidate_with_mask(pos.house_peers(), known);
+        }
+        self.solver.solve(&mut real)?;
+
+        for pos in Position::ALL {
+            let cell_notes = notes.candidates_at(pos);
+            if givens[pos].is_some() || fills[pos].is_some() || cell_notes.is_empty() {
+                continue;
+            }
+            if let Some(digit) = solution.as_ref().and_then(|solution| solution[pos])
+                && !cell_notes.contains(digit)
+            {
+                check.wrongly_eliminated.push(Candidate::new(pos, digit));
+            }
+            let candidates = real.candidates_at(pos);
+            for digit in cell_notes {
+                if !candidates.contains(digit) {
+                    check.dead.push(Candidate::new(pos, digit));
+                }
+            }
+        }
+        Ok(check)
+    }
+}
+
+/// The result of [`NotesChecker::check`].
+///
+/// Candidates are listed in cell order.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct NotesCheck {
+    wrong_fills: Vec<Candidate>,
+    wrongly_eliminated: Vec<Candidate>,
+    dead: Vec<Candidate>,
+}
+
+impl NotesCheck {
+    /// Returns the filled digits that differ from the solution.
+    #[must_use]
+    pub fn wrong_fills(&self) -> &[Candidate] {
+        &self.wrong_fills
+    }
+
+    /// Returns the solution digits missing from the notes of their cell.
+    #[must_use]
+    pub fn wrongly_eliminated(&self) -> &[Candidate] {
+        &self.wrongly_eliminated
+    }
+
+    /// Returns the noted digits that are not real candidates.
+    #[must_use]
+    pub fn dead(&self) -> &[Candidate] {
+        &self.dead
+    }
+
+    /// Returns `true` if no fill is wrong and no note is wrongly eliminated or
+    /// dead.
+    #[must_use]
+    pub fn is_correct(&self) -> bool {
+        self.wrong_fills.is_empty() && self.wrongly_eliminated.is_empty() && self.dead.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::Digit;
+
+    use super::*;
+
+    const SPARSE_PROBLEM: &str =
+        "...36..4..9.....2...67..1..5....987..31..............4..8...65.67.....3......3..2";
+
+    fn puzzle() -> DigitGrid {
+        SPARSE_PROBLEM.parse().unwrap()
+    }
+
+    /// Returns the candidates left by the digits of `grid` in their peers.
+    fn peer_candidates(grid: &DigitGrid) -> CandidateGrid {
+        let mut candidates = CandidateGrid::from_digit_grid(grid);
+        for pos in Position::ALL {
+            if let Some(digit) = grid[pos] {
+                candidates.remove_candidate_with_mask(pos.house_peers(), digit);
+            }
+        }
+        candidates
+    }
+
+    /// Returns notes with `digits` in `pos` only.
+    fn notes_at(pos: Position, digits: &[Digit]) -> CandidateGrid {
+        let mut notes = CandidateGrid::new();
+        for other in Position::ALL {
+            for digit in Digit::ALL {
+                if other != pos || !digits.contains(&digit) {
+                    notes.remove_candidate(other, digit);
+                }
+            }
+        }
+        notes
+    }
+
+    /// Returns the solution of [`SPARSE_PROBLEM`].
+    fn solution() -> DigitGrid {
+        let grid = CandidateGrid::from_digit_grid(&puzzle());
+        match BitboardSolver::new().uniqueness(&grid) {
+            Uniqueness::Unique(solution) => solution,
+            uniqueness => panic!("expected a unique solution, got {uniqueness:?}"),
+        }
+    }
+
+    #[test]
+    fn test_notes_of_peer_candidates_are_correct() {
+        let solver = TechniqueSolver::new(vec![]);
+        let puzzle = puzzle();
+        let notes = peer_candidates(&puzzle);
+        let check = NotesChecker::new(&solver)
+            .check(&puzzle, &DigitGrid::new(), &notes)
+            .unwrap();
+        assert!(check.is_correct());
+        assert_eq!(check, NotesCheck::default());
+    }
+
+    #[test]
+    fn test_reports_dead_and_wrongly_eliminated_notes() {
+        let solver = TechniqueSolver::new(vec![]);
+        // A correct fill counts like a given, and its cell is not checked
+        let mut fills = DigitGrid::new();
+        fills.set(Position::new(1, 0), Some(Digit::D8));
+        let pos = Position::new(0, 0);
+        let notes = notes_at(pos, &[Digit::D2, Digit::D3, Digit::D8]);
+
+        let check = NotesChecker::new(&solver)
+            .check(&puzzle(), &fills, &notes)
+            .unwrap();
+        assert_eq!(check.wrong_fills(), []);
+        assert_eq!(check.wrongly_eliminated(), [Candidate::new(pos, Digit::D1)]);
+        assert_eq!(
+            check.dead(),
+            [
+                Candidate::new(pos, Digit::D3),
+                Candidate::new(pos, Digit::D8)
+            ]
+        );
+        assert!(!check.is_correct());
+    }
+
+    #[test]
+    fn test_wrong_fills_are_reported_and_not_propagated() {
+        let solver = TechniqueSolver::with_fundamental_techniques();
+        let solution = solution();
+        // r1c2 is filled with the solution digit of r1c1, which conflicts with
+        // nothing but is wrong
+        let (pos, filled) = (Position::new(1, 0), Position::new(0, 0));
+        let digit = solution[filled].unwrap();
+        let mut fills = DigitGrid::new();
+        fills.set(pos, Some(digit));
+        let notes = notes_at(filled, &[digit]);
+
+        let check = NotesChecker::new(&solver)
+            .check(&puzzle(), &fills, &notes)
+            .unwrap();
+        assert_eq!(check.wrong_fills(), [Candidate::new(pos, digit)]);
+        // The note of the solution digit is still alive, and not missing
+        assert_eq!(check.wrongly_eliminated(), []);
+        assert_eq!(check.dead(), []);
+        assert!(!check.is_correct());
+    }
+
+    #[test]
+    fn test_ambiguous_puzzle_has_no_wrongly_eliminated_notes() {
+        let solver = TechniqueSolver::new(vec![]);
+        let puzzle: DigitGrid = format!("1{}", ".".repeat(80)).parse().unwrap();
+        let mut fills = DigitGrid::new();
+        fills.set(Position::new(5, 0), Some(Digit::D5));
+        let check = NotesChecker::new(&solver)
+            .check(
+                &puzzle,
+                &fills,
+                &notes_at(Position::new(1, 0), &[Digit::D1, Digit::D5]),
+            )
+            .unwrap();
+        assert_eq!(check.wrong_fills(), []);
+        assert_eq!(check.wrongly_eliminated(), []);
+        // The fill of r1c6 is not propagated, since it cannot be checked
+        assert_eq!(
+            check.dead(),
+            [Candidate::new(Position::new(1, 0), Digit::D1)]
+        );
+    }
+
+    #[test]
+    fn test_techniques_find_more_dead_notes() {
+        let puzzle = puzzle();
+        let notes = peer_candidates(&puzzle);
+        let solver = TechniqueSolver::with_fundamental_techniques();
+        let check = NotesChecker::new(&solver)
+            .check(&puzzle, &DigitGrid::new(), &notes)
+            .unwrap();
+        assert_eq!(check.wrongly_eliminated(), []);
+
+        // The singles solve the puzzle, so only solution digits are alive
+        let empty: Vec<_> = Position::ALL
+            .into_iter()
+            .filter(|&pos| puzzle[pos].is_none())
+            .collect();
+        let candidates: usize = empty
+            .iter()
+            .map(|&pos| notes.candidates_at(pos).len())
+            .sum();
+        assert_eq!(check.dead().len(), candidates - empty.len());
+    }
+
+    #[test]
+    fn test_conflicting_givens_are_inconsistent() {
+        let solver = TechniqueSolver::new(vec![]);
+        let mut puzzle = puzzle();
+        puzzle.set(Position::new(0, 0), Some(Digit::D3));
+        let notes = CandidateGrid::new();
+        assert!(matches!(
+            NotesChecker::new(&solver).check(&puzzle, &DigitGrid::new(), &notes),
+            Err(SolverError::Inconsistent(_))
+        ));
+    }
+}
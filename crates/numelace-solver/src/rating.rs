@@ -0,0 +1,409 @@
+use numelace_core::CandidateGrid;
+
+use crate::{SolverError, Step, TechniqueSolver, TechniqueSolverStats};
+
+/// Weight of a technique missing from [`technique_weight`]'s table.
+const UNKNOWN_TECHNIQUE_WEIGHT: u32 = 60;
+
+/// Weight of a puzzle the techniques cannot solve, which requires guessing.
+const UNSOLVED_WEIGHT: u32 = 90;
+
+/// Returns the weight of the technique named `name`.
+///
+/// Weights range from 10 (naked singles) to 75 (forcing chains) and grow with
+/// the effort a human solver needs to spot the pattern. Techniques without a
+/// known weight, such as custom ones, weigh 60, an expert technique.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_solver::technique_weight;
+///
+/// assert!(technique_weight("hidden singles") < technique_weight("x-wing"));
+/// assert!(technique_weight("x-wing") < technique_weight("xy-chain"));
+/// ```
+#[must_use]
+pub fn technique_weight(name: &str) -> u32 {
+    known_weight(name).unwrap_or(UNKNOWN_TECHNIQUE_WEIGHT)
+}
+
+/// Returns the weight of the technique named `name`, if it is known.
+fn known_weight(name: &str) -> Option<u32> {
+    let weight = match name {
+        "naked singles" => 10,
+        "hidden singles" => 12,
+        "locked candidates" => 20,
+        "naked pairs" => 24,
+        "hidden pairs" => 26,
+        "naked triples" => 28,
+        "hidden triples" => 30,
+        "x-wing" | "skyscraper" | "2-string kite" => 32,
+        "empty rectangle" | "simple coloring" => 34,
+        "xy-wing" => 36,
+        "xyz-wing" | "w-wing" | "swordfish" => 38,
+        "naked quads" | "hidden quads" => 40,
+        "jellyfish" => 42,
+        "unique rectangle type 1" | "unique rectangle type 2" => 44,
+        "unique rectangle type 3" | "unique rectangle type 4" | "hidden unique rectangle" => 46,
+        "bug+1" => 48,
+        "finned x-wing" | "sashimi x-wing" | "multi-coloring" => 50,
+        "finned swordfish" | "sashimi swordfish" => 52,
+        "finned jellyfish" | "sashimi jellyfish" => 54,
+        "sue de coq" | "x-chain" => 56,
+        "xy-chain" => 58,
+        "als-xz" => 60,
+        "als-xy-wing" => 62,
+        "alternating inference chain" => 64,
+        "nishio" => 70,
+        "cell forcing chain" | "digit forcing chain" => 75,
+        _ => return None,
+    };
+    Some(weight)
+}
+
+/// A named difficulty tier, ordered from easiest to hardest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
+pub enum DifficultyTier {
+    /// Singles only.
+    Easy,
+    /// Locked candidates and pairs or triples.
+    Medium,
+    /// Basic fish, quads, single-digit patterns, wings and unique rectangles.
+    Hard,
+    /// Finned fish, coloring clusters, chains and Almost Locked Sets.
+    Expert,
+    /// Forcing techniques, or guessing.
+    Extreme,
+}
+
+impl DifficultyTier {
+    /// Returns the tier of a step of technique weight `weight`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_solver::{DifficultyTier, technique_weight};
+    ///
+    /// let tier = DifficultyTier::from_weight(technique_weight("x-wing"));
+    /// assert_eq!(tier, DifficultyTier::Hard);
+    /// ```
+    #[must_use]
+    pub const fn from_weight(weight: u32) -> Self {
+        match weight {
+            0..20 => Self::Easy,
+            20..32 => Self::Medium,
+            32..50 => Self::Hard,
+            50..70 => Self::Expert,
+            _ => Self::Extreme,
+        }
+    }
+}
+
+/// The difficulty of a puzzle, derived from its solve path.
+///
+/// The score is dominated by the weight of the hardest technique needed (see
+/// [`technique_weight`]), times 100. The remaining two digits break ties between
+/// puzzles with the same hardest technique:
+///
+/// - up to 50 points as the hardest technique is needed more often, and
+/// - up to 49 points as it is first needed earlier in the path, since more of
+///   the puzzle is left to solve around it.
+///
+/// A puzzle the techniques cannot solve scores 9000 and is rated
+/// [`Extreme`](DifficultyTier::Extreme).
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr as _;
+///
+/// use numelace_core::DigitGrid;
+/// use numelace_solver::{DifficultyTier, PuzzleRater, TechniqueSolver};
+///
+/// let grid = DigitGrid::from_str(
+///     "
+///     53_ _7_ ___
+///     6__ 195 ___
+///     _98 ___ _6_
+///     8__ _6_ __3
+///     4__ 8_3 __1
+///     7__ _2_ __6
+///     _6_ ___ 28_
+///     ___ 419 __5
+///     ___ _8_ _79
+///     ",
+/// )?;
+///
+/// let solver = TechniqueSolver::with_all_techniques();
+/// let rating = PuzzleRater::new(&solver).rate(&grid.into())?;
+/// assert!(rating.is_solved());
+/// assert_eq!(rating.tier(), DifficultyTier::Easy);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DifficultyRating {
+    score: u32,
+    tier: DifficultyTier,
+    solved: bool,
+    hardest_technique: Option<&'static str>,
+    hardest_count: usize,
+    steps: usize,
+}
+
+impl DifficultyRating {
+    /// Rates a solve path.
+    ///
+    /// `solved` tells whether the path ends with the puzzle solved.
+    #[must_use]
+    pub fn from_path(path: &[Step], solved: bool) -> Self {
+        let hardest = path
+            .iter()
+            .map(|step| technique_weight(step.technique()))
+            .max()
+            .unwrap_or(0);
+        let first_hardest = path
+            .iter()
+            .position(|step| technique_weight(step.technique()) == hardest);
+        let hardest_technique = first_hardest.map(|i| path[i].technique());
+        let hardest_count = path
+            .iter()
+            .filter(|step| technique_weight(step.technique()) == hardest)
+            .count();
+
+        let (score, tier) = if solved {
+            let repeats = hardest_count.saturating_sub(1).min(5);
+            let repeats = u32::try_from(repeats).unwrap_or(5);
+            let earliness = first_hardest.map_or(0, |i| (path.len() - i) * 49 / path.len());
+            let earliness = u32::try_from(earliness).unwrap_or(49);
+            let score = hardest * 100 + repeats * 10 + earliness;
+            (score, DifficultyTier::from_weight(hardest))
+        } else {
+            (UNSOLVED_WEIGHT * 100, DifficultyTier::Extreme)
+        };
+
+        Self {
+            score,
+            tier,
+            solved,
+            hardest_technique,
+            hardest_count,
+            steps: path.len(),
+        }
+    }
+
+    /// Returns the difficulty score; harder puzzles score higher.
+    #[must_use]
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+
+    /// Returns the difficulty tier.
+    #[must_use]
+    pub fn tier(&self) -> DifficultyTier {
+        self.tier
+    }
+
+    /// Returns `true` if the techniques solved the puzzle.
+    #[must_use]
+    pub fn is_solved(&self) -> bool {
+        self.solved
+    }
+
+    /// Returns the name of the hardest technique applied, or `None` if no step
+    /// was taken.
+    #[must_use]
+    pub fn hardest_technique(&self) -> Option<&'static str> {
+        self.hardest_technique
+    }
+
+    /// Returns how many steps used a technique as hard as the hardest one.
+    #[must_use]
+    pub fn hardest_count(&self) -> usize {
+        self.hardest_count
+    }
+
+    /// Returns the number of steps in the solve path.
+    #[must_use]
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+}
+
+/// Rates puzzles by solving them with a [`TechniqueSolver`].
+///
+/// The solver determines which techniques are available: a puzzle that needs
+/// a technique the solver lacks is rated as unsolved. Use a solver that
+/// includes [`forcing_techniques`](crate::technique::forcing_techniques) to tell
+/// [`Extreme`](DifficultyTier::Extreme) puzzles apart.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::{DifficultyTier, PuzzleRater, TechniqueSolver};
+///
+/// let solver = TechniqueSolver::with_all_techniques();
+/// let rater = PuzzleRater::new(&solver);
+///
+/// // An empty grid cannot be solved by techniques alone
+/// let rating = rater.rate(&CandidateGrid::new())?;
+/// assert!(!rating.is_solved());
+/// assert_eq!(rating.tier(), DifficultyTier::Extreme);
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct PuzzleRater<'a> {
+    solver: &'a TechniqueSolver,
+}
+
+impl<'a> PuzzleRater<'a> {
+    /// Creates a new rater that solves puzzles with `solver`.
+    #[must_use]
+    pub fn new(solver: &'a TechniqueSolver) -> Self {
+        Self { solver }
+    }
+
+    /// Solves a copy of `grid` step by step and rates the path taken.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Inconsistent`] if the grid is or becomes
+    /// inconsistent.
+    pub fn rate(&self, grid: &CandidateGrid) -> Result<DifficultyRating, SolverError> {
+        self.rate_with_stats(grid, &mut TechniqueSolverStats::new())
+    }
+
+    /// Rates `grid` like [`rate`](Self::rate), accumulating the techniques
+    /// applied in `stats`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Inconsistent`] if the grid is or becomes
+    /// inconsistent.
+    pub fn rate_with_stats(
+        &self,
+        grid: &CandidateGrid,
+        stats: &mut TechniqueSolverStats,
+    ) -> Result<DifficultyRating, SolverError> {
+        let mut grid = grid.clone();
+        let mut path = vec![];
+        while let Some(step) = self.solver.step(&mut grid, stats)? {
+            path.push(step);
+        }
+        Ok(DifficultyRating::from_path(&path, grid.is_solved()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{Digit, Position};
+
+    use super::*;
+    use crate::technique;
+
+    fn step(technique: &'static str) -> Step {
+        let before = CandidateGrid::new();
+        let mut after = before.clone();
+        after.remove_candidate(Position::new(0, 0), Digit::D1);
+        Step::from_changes(technique, &before, &after).unwrap()
+    }
+
+    #[test]
+    fn test_every_technique_has_a_weight() {
+        let techniques = technique::all_techniques()
+            .into_iter()
+            .chain(technique::forcing_techniques());
+        for technique in techniques {
+            assert!(
+                known_weight(technique.name()).is_some(),
+                "{} has no weight",
+                technique.name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_techniques_are_ordered_by_weight() {
+        // Forcing techniques are meant to be appended to all_techniques
+        let techniques: Vec<_> = technique::all_techniques()
+            .into_iter()
+            .chain(technique::forcing_techniques())
+            .collect();
+        for pair in techniques.windows(2) {
+            assert!(
+                technique_weight(pair[0].name()) <= technique_weight(pair[1].name()),
+                "{} is harder than {}",
+                pair[0].name(),
+                pair[1].name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_empty_path_is_easy() {
+        let rating = DifficultyRating::from_path(&[], true);
+        assert_eq!(rating.score(), 0);
+        assert_eq!(rating.tier(), DifficultyTier::Easy);
+        assert_eq!(rating.hardest_technique(), None);
+    }
+
+    #[test]
+    fn test_hardest_technique_sets_tier() {
+        let path = [
+            step("naked singles"),
+            step("x-wing"),
+            step("hidden singles"),
+        ];
+        let rating = DifficultyRating::from_path(&path, true);
+        assert_eq!(rating.tier(), DifficultyTier::Hard);
+        assert_eq!(rating.hardest_technique(), Some("x-wing"));
+        assert_eq!(rating.hardest_count(), 1);
+        assert_eq!(rating.score() / 100, technique_weight("x-wing"));
+    }
+
+    #[test]
+    fn test_repeated_and_early_hard_steps_score_higher() {
+        let once = [
+            step("hidden singles"),
+            step("hidden singles"),
+            step("x-wing"),
+        ];
+        let early = [
+            step("x-wing"),
+            step("hidden singles"),
+            step("hidden singles"),
+        ];
+        let twice = [step("x-wing"), step("hidden singles"), step("x-wing")];
+        let once = DifficultyRating::from_path(&once, true);
+        let early = DifficultyRating::from_path(&early, true);
+        let twice = DifficultyRating::from_path(&twice, true);
+        assert!(once.score() < early.score());
+        assert!(early.score() < twice.score());
+        assert_eq!(twice.tier(), once.tier());
+    }
+
+    #[test]
+    fn test_bonus_never_reaches_next_weight() {
+        let path = vec![step("x-wing"); 20];
+        let rating = DifficultyRating::from_path(&path, true);
+        assert!(rating.score() < (technique_weight("x-wing") + 1) * 100);
+    }
+
+    #[test]
+    fn test_unsolved_path_is_extreme() {
+        let rating = DifficultyRating::from_path(&[step("hidden singles")], false);
+        assert_eq!(rating.tier(), DifficultyTier::Extreme);
+        assert_eq!(rating.score(), UNSOLVED_WEIGHT * 100);
+        assert!(!rating.is_solved());
+    }
+
+    #[test]
+    fn test_tiers_are_ordered() {
+        assert_eq!(DifficultyTier::from_weight(10), DifficultyTier::Easy);
+        assert_eq!(DifficultyTier::from_weight(24), DifficultyTier::Medium);
+        assert_eq!(DifficultyTier::from_weight(40), DifficultyTier::Hard);
+        assert_eq!(DifficultyTier::from_weight(58), DifficultyTier::Expert);
+        assert_eq!(DifficultyTier::from_weight(75), DifficultyTier::Extreme);
+        assert!(DifficultyTier::Easy < DifficultyTier::Extreme);
+    }
+}
@@ -0,0 +1,77 @@
+//! Solution counting shared by the brute-force solvers.
+
+use std::convert::Infallible;
+
+use numelace_core::CandidateGrid;
+
+use crate::{
+    BitboardSolutions, BoundedSolutions, DlxSolutions, Solutions, SolverError, Uniqueness,
+};
+
+/// A search that yields the solutions of a puzzle one at a time.
+pub(crate) trait SolutionSearch {
+    /// The error that stops the search before all solutions are found.
+    type Error;
+
+    /// Searches for the next solution, or returns `Ok(None)` once all
+    /// solutions have been found.
+    fn next_solution(&mut self) -> Result<Option<CandidateGrid>, Self::Error>;
+}
+
+impl SolutionSearch for DlxSolutions {
+    type Error = Infallible;
+
+    fn next_solution(&mut self) -> Result<Option<CandidateGrid>, Self::Error> {
+        Ok(self.next())
+    }
+}
+
+impl SolutionSearch for BitboardSolutions {
+    type Error = Infallible;
+
+    fn next_solution(&mut self) -> Result<Option<CandidateGrid>, Self::Error> {
+        Ok(self.next())
+    }
+}
+
+impl SolutionSearch for Solutions<'_> {
+    type Error = Infallible;
+
+    fn next_solution(&mut self) -> Result<Option<CandidateGrid>, Self::Error> {
+        Ok(self.next().map(|(solution, _)| solution))
+    }
+}
+
+impl SolutionSearch for BoundedSolutions<'_> {
+    type Error = SolverError;
+
+    fn next_solution(&mut self) -> Result<Option<CandidateGrid>, Self::Error> {
+        Ok(self.next().transpose()?.map(|(solution, _)| solution))
+    }
+}
+
+/// Counts the solutions of `search`, stopping once `limit` are found.
+pub(crate) fn count_solutions<S>(mut search: S, limit: usize) -> Result<usize, S::Error>
+where
+    S: SolutionSearch,
+{
+    let mut count = 0;
+    while count < limit && search.next_solution()?.is_some() {
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Checks whether `search` yields exactly one solution, stopping at the second.
+pub(crate) fn uniqueness<S>(mut search: S) -> Result<Uniqueness, S::Error>
+where
+    S: SolutionSearch,
+{
+    let Some(solution) = search.next_solution()? else {
+        return Ok(Uniqueness::NoSolution);
+    };
+    if search.next_solution()?.is_some() {
+        return Ok(Uniqueness::Multiple);
+    }
+    Ok(Uniqueness::Unique(solution.to_digit_grid()))
+}
@@ -0,0 +1,289 @@
+use numelace_core::{CandidateGrid, Digit, DigitPositions, House, Position};
+
+/// A single candidate: a digit in a cell.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::{Digit, Position};
+/// use numelace_solver::Candidate;
+///
+/// let candidate = Candidate::new(Position::new(4, 2), Digit::D7);
+/// assert_eq!(candidate.pos(), Position::new(4, 2));
+/// assert_eq!(candidate.digit(), Digit::D7);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Candidate {
+    pos: Position,
+    digit: Digit,
+}
+
+impl Candidate {
+    /// Creates a new candidate for `digit` at `pos`.
+    #[must_use]
+    pub const fn new(pos: Position, digit: Digit) -> Self {
+        Self { pos, digit }
+    }
+
+    /// Returns the position of the candidate.
+    #[must_use]
+    pub const fn pos(self) -> Position {
+        self.pos
+    }
+
+    /// Returns the digit of the candidate.
+    #[must_use]
+    pub const fn digit(self) -> Digit {
+        self.digit
+    }
+}
+
+/// The kind of a link between two candidates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LinkKind {
+    /// At least one of the two candidates is true.
+    Strong,
+    /// At most one of the two candidates is true.
+    Weak,
+}
+
+/// A link between two candidates, as used by chains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Link {
+    from: Candidate,
+    to: Candidate,
+    kind: LinkKind,
+}
+
+impl Link {
+    /// Creates a new link of `kind` from `from` to `to`.
+    #[must_use]
+    pub const fn new(from: Candidate, to: Candidate, kind: LinkKind) -> Self {
+        Self { from, to, kind }
+    }
+
+    /// Returns the candidate the link starts from.
+    #[must_use]
+    pub const fn from(self) -> Candidate {
+        self.from
+    }
+
+    /// Returns the candidate the link leads to.
+    #[must_use]
+    pub const fn to(self) -> Candidate {
+        self.to
+    }
+
+    /// Returns the kind of the link.
+    #[must_use]
+    pub const fn kind(self) -> LinkKind {
+        self.kind
+    }
+}
+
+/// The result of applying a technique once: what changed and why.
+///
+/// A step records the technique that was applied, the digits it placed and the
+/// candidates it eliminated, along with the pattern that justifies them: the
+/// cells and houses involved and, for chains, the links followed.
+///
+/// The eliminations are derived from the grid before and after the technique
+/// was applied (see [`from_changes`](Self::from_changes)), so they always match
+/// the actual changes. The placements and the pattern are supplied by the
+/// technique.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::{CandidateGrid, Digit, Position};
+/// use numelace_solver::{TechniqueSolver, TechniqueSolverStats};
+///
+/// let solver = TechniqueSolver::with_all_techniques();
+/// let mut grid = CandidateGrid::new();
+/// grid.place(Position::new(0, 0), Digit::D5);
+/// let mut stats = TechniqueSolverStats::new();
+///
+/// let step = solver.step(&mut grid, &mut stats)?.unwrap();
+/// assert_eq!(step.technique(), "naked singles");
+/// assert_eq!(step.eliminations().len(), 20);
+/// assert!(step.placements().is_empty());
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    technique: &'static str,
+    placements: Vec<Candidate>,
+    eliminations: Vec<Candidate>,
+    cells: DigitPositions,
+    houses: Vec<House>,
+    links: Vec<Link>,
+}
+
+impl Step {
+    /// Creates a step of `technique` from the grid before and after it was applied.
+    ///
+    /// Every candidate removed from the grid is recorded as an elimination, even
+    /// if it leaves a cell with a single candidate. Techniques that place digits
+    /// use [`from_placements`](Self::from_placements) instead.
+    ///
+    /// Returns `None` if the grid did not change.
+    #[must_use]
+    pub fn from_changes(
+        technique: &'static str,
+        before: &CandidateGrid,
+        after: &CandidateGrid,
+    ) -> Option<Self> {
+        Self::from_placements(technique, before, after, vec![])
+    }
+
+    /// Creates a step of `technique` that placed the digits of `placements`.
+    ///
+    /// The other candidates removed from the cells of `placements` are implied
+    /// by the placements. Candidates removed from the other cells are recorded
+    /// as eliminations.
+    ///
+    /// Returns `None` if the grid did not change.
+    #[must_use]
+    pub fn from_placements(
+        technique: &'static str,
+        before: &CandidateGrid,
+        after: &CandidateGrid,
+        mut placements: Vec<Candidate>,
+    ) -> Option<Self> {
+        let placed: DigitPositions = placements.iter().map(|candidate| candidate.pos).collect();
+        let mut eliminations = vec![];
+        for digit in Digit::ALL {
+            let removed = before.digit_positions(digit) & !after.digit_positions(digit) & !placed;
+            eliminations.extend(removed.iter().map(|pos| Candidate::new(pos, digit)));
+        }
+        if placements.is_empty() && eliminations.is_empty() {
+            return None;
+        }
+        placements.sort_by_key(|candidate| (candidate.pos.y(), candidate.pos.x()));
+        eliminations
+            .sort_by_key(|candidate| (candidate.pos.y(), candidate.pos.x(), candidate.digit));
+        Some(Self {
+            technique,
+            placements,
+            eliminations,
+            cells: DigitPositions::EMPTY,
+            houses: vec![],
+            links: vec![],
+        })
+    }
+
+    /// Sets the cells forming the pattern.
+    #[must_use]
+    pub fn with_cells(mut self, cells: DigitPositions) -> Self {
+        self.cells = cells;
+        self
+    }
+
+    /// Sets the houses the pattern is built on.
+    #[must_use]
+    pub fn with_houses<I>(mut self, houses: I) -> Self
+    where
+        I: IntoIterator<Item = House>,
+    {
+        self.houses = houses.into_iter().collect();
+        self
+    }
+
+    /// Sets the links followed by the pattern.
+    #[must_use]
+    pub fn with_links<I>(mut self, links: I) -> Self
+    where
+        I: IntoIterator<Item = Link>,
+    {
+        self.links = links.into_iter().collect();
+        self
+    }
+
+    /// Returns the name of the technique that was applied.
+    #[must_use]
+    pub fn technique(&self) -> &'static str {
+        self.technique
+    }
+
+    /// Returns the digits placed by the step, ordered by position.
+    #[must_use]
+    pub fn placements(&self) -> &[Candidate] {
+        &self.placements
+    }
+
+    /// Returns the candidates eliminated by the step, ordered by position.
+    ///
+    /// Candidates of placed cells are not included.
+    #[must_use]
+    pub fn eliminations(&self) -> &[Candidate] {
+        &self.eliminations
+    }
+
+    /// Returns the cells forming the pattern.
+    #[must_use]
+    pub fn cells(&self) -> DigitPositions {
+        self.cells
+    }
+
+    /// Returns the houses the pattern is built on.
+    #[must_use]
+    pub fn houses(&self) -> &[House] {
+        &self.houses
+    }
+
+    /// Returns the links followed by the pattern, in order.
+    #[must_use]
+    pub fn links(&self) -> &[Link] {
+        &self.links
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_placements_records_placements_and_eliminations() {
+        let before = CandidateGrid::new();
+        let mut after = before.clone();
+        after.place(Position::new(0, 0), Digit::D1);
+        after.remove_candidate(Position::new(1, 0), Digit::D1);
+
+        let placements = vec![Candidate::new(Position::new(0, 0), Digit::D1)];
+        let step = Step::from_placements("test", &before, &after, placements).unwrap();
+        assert_eq!(step.technique(), "test");
+        assert_eq!(
+            step.placements(),
+            [Candidate::new(Position::new(0, 0), Digit::D1)]
+        );
+        // The other candidates of the placed cell are implied by the placement
+        assert_eq!(
+            step.eliminations(),
+            [Candidate::new(Position::new(1, 0), Digit::D1)]
+        );
+    }
+
+    #[test]
+    fn test_from_changes_records_last_candidate_removal_as_elimination() {
+        let mut before = CandidateGrid::new();
+        // (0, 0) is left with {1, 2}, and then loses 2
+        for digit in Digit::ALL.into_iter().skip(2) {
+            before.remove_candidate(Position::new(0, 0), digit);
+        }
+        let mut after = before.clone();
+        after.remove_candidate(Position::new(0, 0), Digit::D2);
+
+        let step = Step::from_changes("test", &before, &after).unwrap();
+        assert_eq!(step.placements(), []);
+        assert_eq!(
+            step.eliminations(),
+            [Candidate::new(Position::new(0, 0), Digit::D2)]
+        );
+    }
+
+    #[test]
+    fn test_from_changes_returns_none_without_changes() {
+        let grid = CandidateGrid::new();
+        assert!(Step::from_changes("test", &grid, &grid).is_none());
+    }
+}
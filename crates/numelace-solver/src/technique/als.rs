@@ -0,0 +1,194 @@
+//! Detection of Almost Locked Sets.
+//!
+//! An Almost Locked Set (ALS) is a group of N undecided cells within one house
+//! whose candidates contain exactly N+1 digits. If any one of those digits is
+//! removed from the set, the remaining N digits are locked into the N cells.
+//! A single bivalue cell is the smallest ALS.
+
+use std::collections::HashSet;
+
+use numelace_core::{
+    CandidateGrid, Digit, DigitPositions, DigitSet, House, HouseMask,
+    containers::Array9,
+    index::{CellIndexSemantics, DigitSemantics},
+};
+
+use super::combinations::subsets;
+
+/// The maximum number of cells in the sets searched by ALS techniques.
+///
+/// The number of sets grows quickly with their size, and large sets in a house
+/// mostly mirror small ones in the rest of the house.
+pub(crate) const MAX_ALS_SIZE: usize = 4;
+
+/// An Almost Locked Set: N cells in one house holding N+1 candidate digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Als {
+    pub(crate) cells: DigitPositions,
+    pub(crate) digits: DigitSet,
+    /// The cells of the set holding each digit.
+    digit_cells: Array9<DigitPositions, DigitSemantics>,
+    /// The cells that see every cell of the set holding each digit.
+    digit_peers: Array9<DigitPositions, DigitSemantics>,
+}
+
+impl Als {
+    fn new(grid: &CandidateGrid, cells: DigitPositions, digits: DigitSet) -> Self {
+        let digit_cells = Array9::from_fn(|digit| cells & grid.digit_positions(digit));
+        let digit_peers = Array9::from_fn(|digit| {
+            if digits.contains(digit) {
+                common_peers(digit_cells[digit])
+            } else {
+                DigitPositions::EMPTY
+            }
+        });
+        Self {
+            cells,
+            digits,
+            digit_cells,
+            digit_peers,
+        }
+    }
+
+    /// Returns the cells outside the set that see every cell of the set holding `digit`.
+    pub(crate) fn digit_peers(&self, digit: Digit) -> DigitPositions {
+        self.digit_peers[digit] & !self.cells
+    }
+
+    /// Returns the restricted common candidates between two disjoint sets.
+    ///
+    /// A digit is a restricted common candidate if both sets hold it and every
+    /// cell holding it in one set sees every cell holding it in the other. At most
+    /// one of the two sets can then contain the digit, so the other set becomes
+    /// locked.
+    pub(crate) fn restricted_commons(&self, other: &Als) -> DigitSet {
+        let mut commons = DigitSet::EMPTY;
+        if !self.cells.is_disjoint(other.cells) {
+            return commons;
+        }
+        for digit in self.digits & other.digits {
+            if other.digit_cells[digit].is_subset(self.digit_peers[digit]) {
+                commons.insert(digit);
+            }
+        }
+        commons
+    }
+}
+
+/// Returns every Almost Locked Set of at most `max_size` cells.
+///
+/// Smaller sets come first. A set of cells lying in several houses
+/// (such as a single cell, or cells in both a row and a box) is returned once.
+pub(crate) fn almost_locked_sets(grid: &CandidateGrid, max_size: usize) -> Vec<Als> {
+    let undecided = !grid.decided_cells();
+    let mut sets = vec![];
+    let mut seen = HashSet::new();
+    for house in House::ALL {
+        let candidates = Array9::<DigitSet, CellIndexSemantics>::from_fn(|i| {
+            grid.candidates_at(house.position(i))
+        });
+        let mut cells = HouseMask::new();
+        for (i, pos) in (0..).zip(house.cells()) {
+            if undecided.contains(pos) {
+                cells.insert(i);
+            }
+        }
+        // Candidates of each subset, built from the subset without its first cell
+        let mut unions = [DigitSet::EMPTY; 1 << 9];
+        for subset in subsets(cells) {
+            let Some(first) = subset.first() else {
+                continue;
+            };
+            let bits = usize::from(subset.bits());
+            let digits = unions[bits & (bits - 1)] | candidates[first];
+            unions[bits] = digits;
+            let size = subset.len();
+            if size > max_size || digits.len() != size + 1 {
+                continue;
+            }
+            let cells: DigitPositions = subset.iter().map(|i| house.position(i)).collect();
+            if seen.insert(cells) {
+                sets.push(Als::new(grid, cells, digits));
+            }
+        }
+    }
+    // Prefer smaller sets, which yield simpler deductions
+    sets.sort_by_key(|als| als.cells.len());
+    sets
+}
+
+/// Returns the cells outside both sets that see every `z` cell of both sets and
+/// hold `z`.
+pub(crate) fn shared_eliminations(
+    grid: &CandidateGrid,
+    a: &Als,
+    b: &Als,
+    z: Digit,
+) -> DigitPositions {
+    a.digit_peers(z) & b.digit_peers(z) & grid.digit_positions(z)
+}
+
+/// Returns the cells that see every cell of `cells`.
+pub(crate) fn common_peers(cells: DigitPositions) -> DigitPositions {
+    cells
+        .iter()
+        .fold(DigitPositions::FULL, |acc, pos| acc & pos.house_peers())
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn test_almost_locked_sets_in_partial_row() {
+        // Row 0 has three undecided cells holding {2, 3, 4}
+        let mut grid = CandidateGrid::new();
+        let digits = [
+            Digit::D5,
+            Digit::D6,
+            Digit::D7,
+            Digit::D8,
+            Digit::D9,
+            Digit::D1,
+        ];
+        for (x, digit) in (3..9).zip(digits) {
+            grid.place(Position::new(x, 0), digit);
+        }
+        for x in 0..3 {
+            grid.remove_candidate(Position::new(x, 0), Digit::D1);
+        }
+        // (0, 0) is the only bivalue cell
+        for digit in [
+            Digit::D5,
+            Digit::D6,
+            Digit::D7,
+            Digit::D8,
+            Digit::D9,
+            Digit::D4,
+        ] {
+            grid.remove_candidate(Position::new(0, 0), digit);
+        }
+        for x in 1..3 {
+            for digit in [Digit::D5, Digit::D6, Digit::D7, Digit::D8, Digit::D9] {
+                grid.remove_candidate(Position::new(x, 0), digit);
+            }
+        }
+
+        let row: DigitPositions = [0, 1, 2].map(|x| Position::new(x, 0)).into_iter().collect();
+        let sets = almost_locked_sets(&grid, 3);
+        assert!(sets.iter().any(|als| {
+            als.cells == DigitPositions::from_iter([Position::new(0, 0)])
+                && als.digits == DigitSet::from_iter([Digit::D2, Digit::D3])
+        }));
+        // The three cells hold only three digits: a locked set, not an ALS
+        assert!(!sets.iter().any(|als| als.cells == row));
+        // Each cell set is reported once, although row 0 and box 0 both contain it
+        let count = sets
+            .iter()
+            .filter(|als| als.cells == DigitPositions::from_iter([Position::new(0, 0)]))
+            .count();
+        assert_eq!(count, 1);
+    }
+}
@@ -0,0 +1,173 @@
+use numelace_core::{CandidateGrid, DigitSet};
+
+use super::{
+    BoxedTechnique,
+    als::{Als, MAX_ALS_SIZE, almost_locked_sets, shared_eliminations},
+    elimination::DigitElimination,
+};
+use crate::{SolverError, Step, technique::Technique};
+
+/// A technique that joins two Almost Locked Sets through a third pivot set.
+///
+/// Given three disjoint Almost Locked Sets `A`, `B` and the pivot `C` (see
+/// [`AlsXz`](super::AlsXz)), suppose `A` and `C` share a restricted common
+/// candidate `x`, and `B` and `C` share a different restricted common candidate
+/// `y`. `C` cannot hold both `x` and `y` while remaining valid, so at least one
+/// of `A` and `B` is locked. For any digit `z` (other than `x` and `y`) held by
+/// both `A` and `B`, `z` is removed from every cell that sees all `z` cells of
+/// `A` and `B`.
+///
+/// Sets of up to four cells are considered. Only the first wing that yields
+/// eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{AlsXyWing, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = AlsXyWing::new();
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AlsXyWing;
+
+impl AlsXyWing {
+    /// Creates a new `AlsXyWing` technique.
+    #[must_use]
+    pub const fn new() -> Self {
+        AlsXyWing
+    }
+}
+
+impl Technique for AlsXyWing {
+    fn name(&self) -> &'static str {
+        "als-xy-wing"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        let sets = almost_locked_sets(grid, MAX_ALS_SIZE);
+        // Sets linked to each set, with their restricted common candidates
+        let mut links: Vec<Vec<(&Als, DigitSet)>> = vec![vec![]; sets.len()];
+        for (i, a) in sets.iter().enumerate() {
+            for (j, b) in sets.iter().enumerate().skip(i + 1) {
+                let restricted = a.restricted_commons(b);
+                if !restricted.is_empty() {
+                    links[i].push((b, restricted));
+                    links[j].push((a, restricted));
+                }
+            }
+        }
+        for (pivot, wings) in sets.iter().zip(&links) {
+            for (i, &(a, a_restricted)) in wings.iter().enumerate() {
+                for &(b, b_restricted) in &wings[i + 1..] {
+                    if !a.cells.is_disjoint(b.cells) {
+                        continue;
+                    }
+                    // The two links must use different digits
+                    let link_pairs = a_restricted.iter().flat_map(|x| {
+                        b_restricted
+                            .iter()
+                            .filter(move |&y| y != x)
+                            .map(move |y| (x, y))
+                    });
+                    for (x, y) in link_pairs {
+                        for z in (a.digits & b.digits).iter().filter(|&z| z != x && z != y) {
+                            let targets = shared_eliminations(grid, a, b, z) & !pivot.cells;
+                            if !targets.is_empty() {
+                                let elimination = DigitElimination {
+                                    digit: z,
+                                    targets,
+                                    cells: pivot.cells | a.cells | b.cells,
+                                    houses: vec![],
+                                };
+                                return Ok(elimination.apply(self.name(), grid));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, Position};
+
+    use super::*;
+    use crate::testing::{TechniqueTester, restrict};
+
+    #[test]
+    fn test_als_xy_wing() {
+        // A = (0, 0){1, 2}, pivot C = (0, 4){1, 3}, B = (4, 4){3, 5} + (5, 4){5, 2}:
+        // A-C linked by D1, C-B linked by D3, so D2 is in (0, 0) or (5, 4)
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(0, 4), &[Digit::D1, Digit::D3]);
+        restrict(&mut grid, Position::new(4, 4), &[Digit::D3, Digit::D5]);
+        restrict(&mut grid, Position::new(5, 4), &[Digit::D5, Digit::D2]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&AlsXyWing::new())
+            .assert_removed_exact(Position::new(5, 0), [Digit::D2])
+            .assert_no_change(Position::new(4, 0))
+            .assert_no_change(Position::new(0, 8));
+    }
+
+    #[test]
+    fn test_no_change_when_links_share_a_digit() {
+        // Both wings are linked to the pivot by D1 only
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(0, 4), &[Digit::D1, Digit::D3]);
+        restrict(&mut grid, Position::new(5, 4), &[Digit::D1, Digit::D2]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&AlsXyWing::new())
+            .assert_no_change(Position::new(5, 0))
+            .assert_no_change(Position::new(0, 1));
+    }
+
+    #[test]
+    fn test_als_xy_wing_tries_every_pair_of_links() {
+        // Pivot C = (0, 0){1, 2, 4} + (1, 0){1, 3, 4} + (2, 0){2, 3, 4}, A = (0, 1){1, 2}
+        // linked to C by D1 and D2, B = (5, 0){1, 3} linked to C by D1 and D3. Only
+        // the links D2 and D3 leave D1 to eliminate: D1 is in (0, 1) or (5, 0)
+        let mut grid = CandidateGrid::new();
+        restrict(
+            &mut grid,
+            Position::new(0, 0),
+            &[Digit::D1, Digit::D2, Digit::D4],
+        );
+        restrict(
+            &mut grid,
+            Position::new(1, 0),
+            &[Digit::D1, Digit::D3, Digit::D4],
+        );
+        restrict(
+            &mut grid,
+            Position::new(2, 0),
+            &[Digit::D2, Digit::D3, Digit::D4],
+        );
+        restrict(&mut grid, Position::new(0, 1), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(5, 0), &[Digit::D1, Digit::D3]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&AlsXyWing::new())
+            .assert_removed_exact(Position::new(3, 1), [Digit::D1])
+            .assert_removed_exact(Position::new(4, 1), [Digit::D1])
+            .assert_removed_exact(Position::new(5, 1), [Digit::D1])
+            .assert_no_change(Position::new(6, 0))
+            .assert_no_change(Position::new(1, 1));
+    }
+}
@@ -0,0 +1,138 @@
+use numelace_core::CandidateGrid;
+
+use super::{
+    BoxedTechnique,
+    als::{MAX_ALS_SIZE, almost_locked_sets, shared_eliminations},
+    elimination::DigitElimination,
+};
+use crate::{SolverError, Step, technique::Technique};
+
+/// A technique that links two Almost Locked Sets through a restricted common candidate.
+///
+/// An Almost Locked Set (ALS) is a group of N cells in one house holding N+1
+/// candidate digits. Given two disjoint sets `A` and `B`, a digit `x` is a
+/// *restricted common candidate* if every `x` cell of `A` sees every `x` cell of
+/// `B`: `x` can then be true in at most one of the sets, so the other set is
+/// locked on its remaining digits. For any other digit `z` held by both sets,
+/// at least one of them must contain `z`, so `z` is removed from every cell that
+/// sees all `z` cells of both sets.
+///
+/// Sets of up to four cells are considered. Only the first pair of sets that
+/// yields eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{AlsXz, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = AlsXz::new();
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AlsXz;
+
+impl AlsXz {
+    /// Creates a new `AlsXz` technique.
+    #[must_use]
+    pub const fn new() -> Self {
+        AlsXz
+    }
+}
+
+impl Technique for AlsXz {
+    fn name(&self) -> &'static str {
+        "als-xz"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        let sets = almost_locked_sets(grid, MAX_ALS_SIZE);
+        for (i, a) in sets.iter().enumerate() {
+            for b in &sets[i + 1..] {
+                // A restricted common candidate and another common digit are needed
+                if (a.digits & b.digits).len() < 2 {
+                    continue;
+                }
+                for x in a.restricted_commons(b) {
+                    for z in (a.digits & b.digits).iter().filter(|&z| z != x) {
+                        let targets = shared_eliminations(grid, a, b, z);
+                        if !targets.is_empty() {
+                            let elimination = DigitElimination {
+                                digit: z,
+                                targets,
+                                cells: a.cells | b.cells,
+                                houses: vec![],
+                            };
+                            return Ok(elimination.apply(self.name(), grid));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, Position};
+
+    use super::*;
+    use crate::testing::{TechniqueTester, restrict};
+
+    #[test]
+    fn test_als_xz() {
+        // A = (0, 0){1, 2}, B = (0, 4){1, 3} + (2, 4){2, 3}, linked by D1 in column 0:
+        // D2 is in (0, 0) or (2, 4)
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(0, 4), &[Digit::D1, Digit::D3]);
+        restrict(&mut grid, Position::new(2, 4), &[Digit::D2, Digit::D3]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&AlsXz::new())
+            .assert_removed_exact(Position::new(2, 0), [Digit::D2])
+            .assert_removed_exact(Position::new(2, 2), [Digit::D2])
+            .assert_removed_exact(Position::new(0, 3), [Digit::D2])
+            .assert_no_change(Position::new(1, 1))
+            .assert_no_change(Position::new(0, 8));
+    }
+
+    #[test]
+    fn test_no_change_without_restricted_common() {
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(4, 4), &[Digit::D1, Digit::D2]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&AlsXz::new())
+            .assert_no_change(Position::new(4, 0))
+            .assert_no_change(Position::new(0, 4));
+    }
+
+    #[test]
+    fn test_als_xz_tries_every_restricted_common() {
+        // A = (0, 0){1, 2} and B = (0, 4){1, 2} are linked by D1 and D2. D2 is
+        // already gone from the rest of column 0, so only the link D2 eliminates
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(0, 4), &[Digit::D1, Digit::D2]);
+        for y in [1, 2, 3, 5, 6, 7, 8] {
+            grid.remove_candidate(Position::new(0, y), Digit::D2);
+        }
+
+        TechniqueTester::new(grid)
+            .apply_once(&AlsXz::new())
+            .assert_removed_exact(Position::new(0, 2), [Digit::D1])
+            .assert_removed_exact(Position::new(0, 8), [Digit::D1])
+            .assert_no_change(Position::new(1, 1));
+    }
+}
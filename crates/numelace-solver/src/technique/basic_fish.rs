@@ -0,0 +1,295 @@
+use numelace_core::{CandidateGrid, Digit, DigitPositions, House, HouseMask, Position};
+
+use super::{BoxedTechnique, combinations::subsets_of_size, elimination::DigitElimination};
+use crate::{SolverError, Step, technique::Technique};
+
+/// A technique that finds N lines whose candidates for a digit are confined to N cross lines.
+///
+/// When a digit's candidates in N rows (the *base* lines) all lie within the same
+/// N columns (the *cover* lines), the digit must be placed once in each base row
+/// at the intersections. It is therefore removed from the rest of the cover
+/// columns. The same applies with rows and columns swapped.
+///
+/// The fish size is a parameter:
+///
+/// - 2: X-Wing
+/// - 3: Swordfish
+/// - 4: Jellyfish
+///
+/// Only the first fish that yields eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{BasicFish, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = BasicFish::new(2);
+/// assert_eq!(technique.name(), "x-wing");
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BasicFish {
+    size: usize,
+}
+
+impl BasicFish {
+    /// Creates a new `BasicFish` technique with the given number of base lines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is not in the range 2-4.
+    #[must_use]
+    pub const fn new(size: usize) -> Self {
+        assert!(size >= 2 && size <= 4);
+        Self { size }
+    }
+
+    /// Returns the number of base (and cover) lines.
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl Technique for BasicFish {
+    fn name(&self) -> &'static str {
+        match self.size {
+            2 => "x-wing",
+            3 => "swordfish",
+            4 => "jellyfish",
+            _ => unreachable!(),
+        }
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        for digit in Digit::ALL {
+            for orientation in [Orientation::Rows, Orientation::Columns] {
+                if let Some(elimination) = self.find(grid, digit, orientation) {
+                    return Ok(elimination.apply(self.name(), grid));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl BasicFish {
+    /// Finds a fish for `digit` with base lines in the given orientation.
+    fn find(
+        self,
+        grid: &CandidateGrid,
+        digit: Digit,
+        orientation: Orientation,
+    ) -> Option<DigitElimination> {
+        let digit_positions = grid.digit_positions(digit);
+
+        // Lines with a single candidate are already hidden singles, and lines
+        // with more than `size` candidates cannot be covered by `size` lines.
+        let mut base_candidates = HouseMask::new();
+        for line in 0..9 {
+            let len = grid.house_mask(orientation.base(line), digit).len();
+            if (2..=self.size).contains(&len) {
+                base_candidates.insert(line);
+            }
+        }
+
+        for base in subsets_of_size(base_candidates, self.size) {
+            let cover = base.iter().fold(HouseMask::EMPTY, |acc, line| {
+                acc | grid.house_mask(orientation.base(line), digit)
+            });
+            if cover.len() != self.size {
+                continue;
+            }
+
+            let base_positions = lines_positions(base, |line| orientation.base(line));
+            let cover_positions = lines_positions(cover, |line| orientation.cover(line));
+            let eliminations = cover_positions & !base_positions & digit_positions;
+            if !eliminations.is_empty() {
+                let houses = base
+                    .iter()
+                    .map(|line| orientation.base(line))
+                    .chain(cover.iter().map(|line| orientation.cover(line)))
+                    .collect();
+                return Some(DigitElimination {
+                    digit,
+                    targets: eliminations,
+                    cells: base_positions & digit_positions,
+                    houses,
+                });
+            }
+        }
+        None
+    }
+}
+
+/// Which kind of line the base lines of a fish are.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Orientation {
+    /// Base lines are rows; cover lines are columns.
+    Rows,
+    /// Base lines are columns; cover lines are rows.
+    Columns,
+}
+
+impl Orientation {
+    /// Returns the base line with the given index.
+    pub(crate) const fn base(self, index: u8) -> House {
+        match self {
+            Orientation::Rows => House::Row { y: index },
+            Orientation::Columns => House::Column { x: index },
+        }
+    }
+
+    /// Returns the cover line with the given index.
+    pub(crate) const fn cover(self, index: u8) -> House {
+        match self {
+            Orientation::Rows => House::Column { x: index },
+            Orientation::Columns => House::Row { y: index },
+        }
+    }
+
+    /// Returns the index of the base line that passes through `pos`.
+    pub(crate) const fn base_index(self, pos: Position) -> u8 {
+        match self {
+            Orientation::Rows => pos.y(),
+            Orientation::Columns => pos.x(),
+        }
+    }
+
+    /// Returns the index of the cover line that passes through `pos`.
+    pub(crate) const fn cover_index(self, pos: Position) -> u8 {
+        match self {
+            Orientation::Rows => pos.x(),
+            Orientation::Columns => pos.y(),
+        }
+    }
+
+    /// Returns the index of the box where the given band of base lines (0-2)
+    /// meets the given band of cover lines (0-2).
+    pub(crate) const fn box_index(self, base_band: u8, cover_band: u8) -> u8 {
+        match self {
+            Orientation::Rows => base_band * 3 + cover_band,
+            Orientation::Columns => cover_band * 3 + base_band,
+        }
+    }
+}
+
+/// Returns the union of the positions of the lines selected by `lines`.
+pub(crate) fn lines_positions(lines: HouseMask, house: impl Fn(u8) -> House) -> DigitPositions {
+    lines.iter().fold(DigitPositions::EMPTY, |acc, line| {
+        acc | house(line).positions()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit};
+
+    use super::*;
+    use crate::testing::TechniqueTester;
+
+    /// Removes `digit` from every cell of the given rows except the given columns.
+    fn confine_rows(grid: &mut CandidateGrid, digit: Digit, rows: &[u8], cols: &[u8]) {
+        for &y in rows {
+            for x in 0..9 {
+                if !cols.contains(&x) {
+                    grid.remove_candidate(Position::new(x, y), digit);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_x_wing_in_rows() {
+        // D1 in rows 1 and 5 is confined to columns 2 and 7
+        let mut grid = CandidateGrid::new();
+        confine_rows(&mut grid, Digit::D1, &[1, 5], &[2, 7]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&BasicFish::new(2))
+            .assert_removed_exact(Position::new(2, 0), [Digit::D1])
+            .assert_removed_exact(Position::new(7, 8), [Digit::D1])
+            .assert_no_change(Position::new(2, 1))
+            .assert_no_change(Position::new(7, 5))
+            .assert_no_change(Position::new(3, 0));
+    }
+
+    #[test]
+    fn test_x_wing_in_columns() {
+        // D4 in columns 0 and 8 is confined to rows 3 and 6
+        let mut grid = CandidateGrid::new();
+        for x in [0, 8] {
+            for y in 0..9 {
+                if y != 3 && y != 6 {
+                    grid.remove_candidate(Position::new(x, y), Digit::D4);
+                }
+            }
+        }
+
+        TechniqueTester::new(grid)
+            .apply_once(&BasicFish::new(2))
+            .assert_removed_exact(Position::new(4, 3), [Digit::D4])
+            .assert_removed_exact(Position::new(1, 6), [Digit::D4])
+            .assert_no_change(Position::new(0, 3))
+            .assert_no_change(Position::new(4, 4));
+    }
+
+    #[test]
+    fn test_swordfish() {
+        // D6 in rows 0, 4, 8 is confined to columns 1, 4, 7, with two candidates per row
+        let mut grid = CandidateGrid::new();
+        confine_rows(&mut grid, Digit::D6, &[0], &[1, 4]);
+        confine_rows(&mut grid, Digit::D6, &[4], &[4, 7]);
+        confine_rows(&mut grid, Digit::D6, &[8], &[1, 7]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&BasicFish::new(3))
+            .assert_removed_exact(Position::new(1, 2), [Digit::D6])
+            .assert_removed_exact(Position::new(4, 6), [Digit::D6])
+            .assert_removed_exact(Position::new(7, 1), [Digit::D6])
+            .assert_no_change(Position::new(1, 0))
+            .assert_no_change(Position::new(0, 2));
+    }
+
+    #[test]
+    fn test_jellyfish() {
+        // D9 in rows 0, 2, 5, 7 is confined to columns 0, 3, 5, 8
+        let mut grid = CandidateGrid::new();
+        confine_rows(&mut grid, Digit::D9, &[0], &[0, 3]);
+        confine_rows(&mut grid, Digit::D9, &[2], &[3, 5]);
+        confine_rows(&mut grid, Digit::D9, &[5], &[5, 8]);
+        confine_rows(&mut grid, Digit::D9, &[7], &[0, 8]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&BasicFish::new(4))
+            .assert_removed_exact(Position::new(0, 1), [Digit::D9])
+            .assert_removed_exact(Position::new(3, 8), [Digit::D9])
+            .assert_removed_exact(Position::new(5, 4), [Digit::D9])
+            .assert_removed_exact(Position::new(8, 6), [Digit::D9])
+            .assert_no_change(Position::new(1, 1))
+            .assert_no_change(Position::new(0, 7));
+    }
+
+    #[test]
+    fn test_no_change_when_cover_too_large() {
+        // D2 in rows 1 and 5 spans three columns
+        let mut grid = CandidateGrid::new();
+        confine_rows(&mut grid, Digit::D2, &[1], &[2, 7]);
+        confine_rows(&mut grid, Digit::D2, &[5], &[2, 6]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&BasicFish::new(2))
+            .assert_no_change(Position::new(2, 0))
+            .assert_no_change(Position::new(7, 0))
+            .assert_no_change(Position::new(6, 0));
+    }
+}
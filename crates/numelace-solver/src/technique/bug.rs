@@ -0,0 +1,158 @@
+use numelace_core::{CandidateGrid, Digit, DigitPositions, House, Position};
+
+use super::BoxedTechnique;
+use crate::{Candidate, SolverError, Step, technique::Technique};
+
+/// A technique that resolves a Bivalue Universal Grave with a single extra candidate.
+///
+/// A *Bivalue Universal Grave* (BUG) is a state in which every undecided cell has
+/// exactly two candidates, and every digit appears as a candidate exactly zero or
+/// two times in each house. Such a state has either no solution or several, so it
+/// cannot occur in a puzzle with a unique solution.
+///
+/// In BUG+1, every undecided cell is bivalue except one cell with three
+/// candidates. If removing one of its candidates would leave a BUG, that candidate
+/// must be true and is placed in the cell.
+///
+/// This technique is only valid for puzzles with a unique solution (see
+/// [`Technique::requires_unique_solution`]).
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{BugPlusOne, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = BugPlusOne::new();
+/// assert!(technique.requires_unique_solution());
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BugPlusOne;
+
+impl BugPlusOne {
+    /// Creates a new `BugPlusOne` technique.
+    #[must_use]
+    pub const fn new() -> Self {
+        BugPlusOne
+    }
+}
+
+impl Technique for BugPlusOne {
+    fn name(&self) -> &'static str {
+        "bug+1"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn requires_unique_solution(&self) -> bool {
+        true
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        let [empty, decided, bivalue, trivalue] = grid.classify_cells::<4>();
+        if !empty.is_empty() || trivalue.len() != 1 {
+            return Ok(None);
+        }
+        // Every other undecided cell must be bivalue
+        if (decided | bivalue | trivalue) != DigitPositions::FULL {
+            return Ok(None);
+        }
+        let Some(pos) = trivalue.first() else {
+            return Ok(None);
+        };
+        for digit in grid.candidates_at(pos) {
+            if leaves_bug(grid, decided, pos, digit) {
+                let before = grid.clone();
+                grid.place(pos, digit);
+                // The pattern is the grave: every undecided cell
+                let cells = !decided;
+                let placements = vec![Candidate::new(pos, digit)];
+                return Ok(
+                    Step::from_placements(self.name(), &before, grid, placements)
+                        .map(|step| step.with_cells(cells)),
+                );
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Returns `true` if removing `digit` from `pos` leaves every digit with zero or
+/// two candidates in each house.
+fn leaves_bug(grid: &CandidateGrid, decided: DigitPositions, pos: Position, digit: Digit) -> bool {
+    Digit::ALL.into_iter().all(|d| {
+        let mut positions = grid.digit_positions(d) & !decided;
+        if d == digit {
+            positions.remove(pos);
+        }
+        House::ALL
+            .iter()
+            .all(|house| matches!((positions & house.positions()).len(), 0 | 2))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, Position};
+
+    use super::*;
+    use crate::testing::{TechniqueTester, restrict};
+
+    /// Returns a grid whose only undecided cells are (3, 0), (4, 0), (3, 3) and (4, 3).
+    fn rectangle_grid() -> CandidateGrid {
+        let mut grid: CandidateGrid = "
+            534 __8 912
+            672 195 348
+            198 342 567
+            859 __1 423
+            426 853 791
+            713 924 856
+            961 537 284
+            287 419 635
+            345 286 179
+        "
+        .parse::<numelace_core::DigitGrid>()
+        .unwrap()
+        .into();
+        for pos in [
+            Position::new(4, 0),
+            Position::new(3, 3),
+            Position::new(4, 3),
+        ] {
+            restrict(&mut grid, pos, &[Digit::D6, Digit::D7]);
+        }
+        grid
+    }
+
+    #[test]
+    fn test_places_extra_candidate() {
+        let mut grid = rectangle_grid();
+        restrict(
+            &mut grid,
+            Position::new(3, 0),
+            &[Digit::D6, Digit::D7, Digit::D1],
+        );
+
+        TechniqueTester::new(grid)
+            .apply_once(&BugPlusOne::new())
+            .assert_placed(Position::new(3, 0), Digit::D1)
+            .assert_no_change(Position::new(4, 3));
+    }
+
+    #[test]
+    fn test_no_change_without_trivalue_cell() {
+        let mut grid = rectangle_grid();
+        restrict(&mut grid, Position::new(3, 0), &[Digit::D6, Digit::D7]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&BugPlusOne::new())
+            .assert_no_change(Position::new(3, 0));
+    }
+}
@@ -0,0 +1,309 @@
+//! A search engine for alternating inference chains.
+//!
+//! Candidates (a digit in a cell) are connected by two kinds of links:
+//!
+//! - A **strong link** joins two candidates of which at least one is true: the
+//!   two candidates of a digit in a house (a conjugate pair), or the two
+//!   candidates of a bivalue cell.
+//! - A **weak link** joins two candidates of which at most one is true: two
+//!   candidates of a digit in cells that see each other, or two candidates in
+//!   the same cell. Every strong link is also a weak link.
+//!
+//! An alternating inference chain (AIC) starts and ends with a strong link and
+//! alternates strong and weak links in between. If its first candidate is false,
+//! its last candidate is true, so at least one of the two endpoints is true.
+
+use std::collections::VecDeque;
+
+use numelace_core::{
+    CandidateGrid, Digit, DigitPositions, House, Position, containers::Array9,
+    index::DigitSemantics,
+};
+
+use super::links::conjugate_pair;
+use crate::{Candidate, Link, LinkKind, Step};
+
+/// The links a chain search may use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LinkRules {
+    /// Links between candidates of the same digit only (X-Chains).
+    SingleDigit,
+    /// Strong links within bivalue cells, and weak links between bivalue cells
+    /// holding the same digit (XY-Chains).
+    Bivalue,
+    /// All strong and weak links (AICs).
+    All,
+}
+
+impl LinkRules {
+    const fn digit_strong_links(self) -> bool {
+        matches!(self, Self::SingleDigit | Self::All)
+    }
+
+    const fn cell_links(self) -> bool {
+        matches!(self, Self::Bivalue | Self::All)
+    }
+}
+
+/// The conclusion of a chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ChainConclusion {
+    /// The chain starts and ends at the same candidate, which must be true
+    /// (a discontinuous loop with two strong links at the discontinuity).
+    Placement(Candidate),
+    /// Candidates weakly linked to both endpoints, which must be false.
+    Eliminations(Vec<Candidate>),
+}
+
+/// An alternating inference chain with a productive conclusion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Chain {
+    /// The candidates of the chain in order, starting and ending with a strong link.
+    pub(crate) nodes: Vec<Candidate>,
+    pub(crate) conclusion: ChainConclusion,
+}
+
+impl Chain {
+    /// Applies the conclusion to the grid, returning the step of `technique` if
+    /// the grid changed.
+    pub(crate) fn apply(&self, technique: &'static str, grid: &mut CandidateGrid) -> Option<Step> {
+        let before = grid.clone();
+        match &self.conclusion {
+            ChainConclusion::Placement(candidate) => {
+                grid.place(candidate.pos(), candidate.digit());
+            }
+            ChainConclusion::Eliminations(candidates) => {
+                for candidate in candidates {
+                    grid.remove_candidate(candidate.pos(), candidate.digit());
+                }
+            }
+        }
+        let cells = self.nodes.iter().map(|node| node.pos()).collect();
+        Some(
+            Step::from_changes(technique, &before, grid)?
+                .with_cells(cells)
+                .with_links(self.links()),
+        )
+    }
+
+    /// Returns the links between consecutive candidates, alternating strong and weak.
+    fn links(&self) -> impl Iterator<Item = Link> + '_ {
+        self.nodes.windows(2).zip(0..).map(|(pair, i)| {
+            let kind = if i % 2 == 0 {
+                LinkKind::Strong
+            } else {
+                LinkKind::Weak
+            };
+            Link::new(pair[0], pair[1], kind)
+        })
+    }
+}
+
+/// Finds the first chain of at most `max_length` candidates that yields a
+/// placement or eliminations.
+///
+/// Chains are searched breadth-first from each starting candidate, so the
+/// shortest productive chain from a given start is found first.
+pub(crate) fn find_chain(
+    grid: &CandidateGrid,
+    rules: LinkRules,
+    max_length: usize,
+) -> Option<Chain> {
+    let graph = LinkGraph::new(grid, rules);
+    for digit in Digit::ALL {
+        for pos in graph.nodes[digit] {
+            if let Some(chain) = graph.search(Candidate::new(pos, digit), max_length) {
+                return Some(chain);
+            }
+        }
+    }
+    None
+}
+
+/// The candidates that may appear in a chain, and the rules for linking them.
+struct LinkGraph<'a> {
+    grid: &'a CandidateGrid,
+    rules: LinkRules,
+    nodes: Array9<DigitPositions, DigitSemantics>,
+    /// The decided cells of each digit, which may not be removed from their
+    /// peers yet.
+    placed: Array9<DigitPositions, DigitSemantics>,
+}
+
+/// A candidate reached during the search.
+struct Visit {
+    candidate: Candidate,
+    /// Whether the candidate is implied to be true (reached through a strong link).
+    on: bool,
+    /// The index of the previous candidate in the search arena.
+    parent: Option<usize>,
+    length: usize,
+}
+
+impl<'a> LinkGraph<'a> {
+    fn new(grid: &'a CandidateGrid, rules: LinkRules) -> Self {
+        let [_, decided, bivalue] = grid.classify_cells::<3>();
+        let allowed = match rules {
+            LinkRules::Bivalue => bivalue,
+            LinkRules::SingleDigit | LinkRules::All => !decided,
+        };
+        let nodes = Array9::from_fn(|digit| grid.digit_positions(digit) & allowed);
+        let placed = Array9::from_fn(|digit| grid.digit_positions(digit) & decided);
+        Self {
+            grid,
+            rules,
+            nodes,
+            placed,
+        }
+    }
+
+    /// Searches chains starting at `start`, assumed false.
+    fn search(&self, start: Candidate, max_length: usize) -> Option<Chain> {
+        let mut visited = [Array9::<DigitPositions, DigitSemantics>::default(); 2];
+        let mut arena = vec![Visit {
+            candidate: start,
+            on: false,
+            parent: None,
+            length: 1,
+        }];
+        let mut queue = VecDeque::from([0]);
+        visited[0][start.digit()].insert(start.pos());
+
+        while let Some(index) = queue.pop_front() {
+            let visit = &arena[index];
+            let (current, on, length) = (visit.candidate, visit.on, visit.length);
+            if on {
+                let conclusion = conclusion(self.grid, start, current);
+                if let Some(conclusion) = conclusion {
+                    return Some(Chain {
+                        nodes: trace(&arena, index),
+                        conclusion,
+                    });
+                }
+            }
+            if length >= max_length {
+                continue;
+            }
+            let next = if on {
+                self.weak_links(current)
+            } else {
+                self.strong_links(current)
+            };
+            for candidate in next {
+                let seen = &mut visited[usize::from(!on)][candidate.digit()];
+                if seen.contains(candidate.pos()) {
+                    continue;
+                }
+                seen.insert(candidate.pos());
+                arena.push(Visit {
+                    candidate,
+                    on: !on,
+                    parent: Some(index),
+                    length: length + 1,
+                });
+                queue.push_back(arena.len() - 1);
+            }
+        }
+        None
+    }
+
+    /// Returns the candidates that must be true if `candidate` is false.
+    fn strong_links(&self, candidate: Candidate) -> Vec<Candidate> {
+        let (pos, digit) = (candidate.pos(), candidate.digit());
+        let mut links = vec![];
+        if self.rules.digit_strong_links() {
+            for house in houses_of(pos) {
+                // A house where the digit is placed has no conjugate pair
+                if !(self.placed[digit] & house.positions()).is_empty() {
+                    continue;
+                }
+                if let Some(ends) = conjugate_pair(self.nodes[digit], house) {
+                    let other = if ends[0] == pos { ends[1] } else { ends[0] };
+                    links.push(Candidate::new(other, digit));
+                }
+            }
+        }
+        if self.rules.cell_links() {
+            let candidates = self.grid.candidates_at(pos);
+            if candidates.len() == 2 {
+                links.extend(
+                    candidates
+                        .iter()
+                        .filter(|&d| d != digit)
+                        .map(|d| Candidate::new(pos, d)),
+                );
+            }
+        }
+        links
+    }
+
+    /// Returns the candidates that must be false if `candidate` is true.
+    fn weak_links(&self, candidate: Candidate) -> Vec<Candidate> {
+        let (pos, digit) = (candidate.pos(), candidate.digit());
+        let mut links: Vec<_> = (pos.house_peers() & self.nodes[digit])
+            .iter()
+            .map(|peer| Candidate::new(peer, digit))
+            .collect();
+        if self.rules.cell_links() {
+            links.extend(
+                self.grid
+                    .candidates_at(pos)
+                    .iter()
+                    .filter(|&d| d != digit && self.nodes[d].contains(pos))
+                    .map(|d| Candidate::new(pos, d)),
+            );
+        }
+        links
+    }
+}
+
+/// Returns the houses containing `pos`.
+fn houses_of(pos: Position) -> [House; 3] {
+    [
+        House::Row { y: pos.y() },
+        House::Column { x: pos.x() },
+        House::Box {
+            index: pos.box_index(),
+        },
+    ]
+}
+
+/// Returns the candidates of the chain ending at `index`, from start to end.
+fn trace(arena: &[Visit], index: usize) -> Vec<Candidate> {
+    let mut nodes = vec![];
+    let mut current = Some(index);
+    while let Some(index) = current {
+        nodes.push(arena[index].candidate);
+        current = arena[index].parent;
+    }
+    nodes.reverse();
+    nodes
+}
+
+/// Returns what follows from at least one of `start` and `end` being true.
+fn conclusion(grid: &CandidateGrid, start: Candidate, end: Candidate) -> Option<ChainConclusion> {
+    if start == end {
+        return Some(ChainConclusion::Placement(start));
+    }
+    let mut eliminations = vec![];
+    if start.pos() == end.pos() {
+        eliminations.extend(
+            grid.candidates_at(start.pos())
+                .iter()
+                .filter(|&d| d != start.digit() && d != end.digit())
+                .map(|d| Candidate::new(start.pos(), d)),
+        );
+    } else if start.digit() == end.digit() {
+        let targets = start.pos().house_peers()
+            & end.pos().house_peers()
+            & grid.digit_positions(start.digit());
+        eliminations.extend(targets.iter().map(|pos| Candidate::new(pos, start.digit())));
+    } else if start.pos().house_peers().contains(end.pos()) {
+        for (pos, digit) in [(start.pos(), end.digit()), (end.pos(), start.digit())] {
+            if grid.candidates_at(pos).contains(digit) {
+                eliminations.push(Candidate::new(pos, digit));
+            }
+        }
+    }
+    (!eliminations.is_empty()).then_some(ChainConclusion::Eliminations(eliminations))
+}
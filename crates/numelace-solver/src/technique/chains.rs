@@ -0,0 +1,436 @@
+use numelace_core::CandidateGrid;
+
+use super::{
+    BoxedTechnique,
+    chain::{LinkRules, find_chain},
+};
+use crate::{SolverError, Step, technique::Technique};
+
+/// The default maximum number of candidates in a chain.
+pub const DEFAULT_MAX_CHAIN_LENGTH: usize = 12;
+
+/// A technique that follows alternating conjugate pairs and weak links of a single digit.
+///
+/// An X-Chain is an alternating inference chain in which every candidate has the
+/// same digit. Strong links are conjugate pairs, and weak links join cells that see
+/// each other. Since at least one end of the chain holds the digit, it is removed
+/// from every cell that sees both ends.
+///
+/// If the chain returns to its first candidate through a strong link, that
+/// candidate cannot be false and the digit is placed (a discontinuous loop).
+///
+/// `max_length` bounds the number of candidates in a chain. Only the first chain
+/// that yields a placement or eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{Technique, XChain};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = XChain::new(8);
+/// assert_eq!(technique.max_length(), 8);
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct XChain {
+    max_length: usize,
+}
+
+impl XChain {
+    /// Creates a new `XChain` technique for chains of at most `max_length` candidates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_length` is less than 2.
+    #[must_use]
+    pub const fn new(max_length: usize) -> Self {
+        assert!(max_length >= 2);
+        Self { max_length }
+    }
+
+    /// Returns the maximum number of candidates in a chain.
+    #[must_use]
+    pub const fn max_length(&self) -> usize {
+        self.max_length
+    }
+}
+
+impl Default for XChain {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CHAIN_LENGTH)
+    }
+}
+
+impl Technique for XChain {
+    fn name(&self) -> &'static str {
+        "x-chain"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        let chain = find_chain(grid, LinkRules::SingleDigit, self.max_length);
+        Ok(chain.and_then(|chain| chain.apply(self.name(), grid)))
+    }
+}
+
+/// A technique that follows a chain of bivalue cells.
+///
+/// An XY-Chain is an alternating inference chain made of bivalue cells only. The
+/// strong links are the two candidates within each cell, and the weak links join
+/// cells that see each other and share the digit being passed along. When both
+/// ends of the chain carry the same digit, it is removed from every cell that sees
+/// both ends.
+///
+/// `max_length` bounds the number of candidates in a chain (two per cell). Only
+/// the first chain that yields a placement or eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{Technique, XyChain};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = XyChain::default();
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct XyChain {
+    max_length: usize,
+}
+
+impl XyChain {
+    /// Creates a new `XyChain` technique for chains of at most `max_length` candidates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_length` is less than 2.
+    #[must_use]
+    pub const fn new(max_length: usize) -> Self {
+        assert!(max_length >= 2);
+        Self { max_length }
+    }
+
+    /// Returns the maximum number of candidates in a chain.
+    #[must_use]
+    pub const fn max_length(&self) -> usize {
+        self.max_length
+    }
+}
+
+impl Default for XyChain {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CHAIN_LENGTH)
+    }
+}
+
+impl Technique for XyChain {
+    fn name(&self) -> &'static str {
+        "xy-chain"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        let chain = find_chain(grid, LinkRules::Bivalue, self.max_length);
+        Ok(chain.and_then(|chain| chain.apply(self.name(), grid)))
+    }
+}
+
+/// A technique that follows alternating strong and weak links between any candidates.
+///
+/// An Alternating Inference Chain (AIC) mixes links between candidates of the same
+/// digit in a house with links between the candidates of a single cell. Since at
+/// least one end of the chain is true, any candidate weakly linked to both ends
+/// is removed:
+///
+/// - Ends with the same digit: the digit is removed from cells that see both ends.
+/// - Ends in the same cell: the cell's other candidates are removed.
+/// - Ends with different digits in cells that see each other: each end's digit is
+///   removed from the other end's cell.
+///
+/// Discontinuous loops are covered as well. A chain that returns to its first
+/// candidate through a strong link places that candidate, and a candidate weakly
+/// linked to both ends of a chain is eliminated as above.
+///
+/// `max_length` bounds the number of candidates in a chain. Only the first chain
+/// that yields a placement or eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{Aic, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = Aic::new(16);
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Aic {
+    max_length: usize,
+}
+
+impl Aic {
+    /// Creates a new `Aic` technique for chains of at most `max_length` candidates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_length` is less than 2.
+    #[must_use]
+    pub const fn new(max_length: usize) -> Self {
+        assert!(max_length >= 2);
+        Self { max_length }
+    }
+
+    /// Returns the maximum number of candidates in a chain.
+    #[must_use]
+    pub const fn max_length(&self) -> usize {
+        self.max_length
+    }
+}
+
+impl Default for Aic {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CHAIN_LENGTH)
+    }
+}
+
+impl Technique for Aic {
+    fn name(&self) -> &'static str {
+        "alternating inference chain"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        let chain = find_chain(grid, LinkRules::All, self.max_length);
+        Ok(chain.and_then(|chain| chain.apply(self.name(), grid)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, House, Position};
+
+    use super::*;
+    use crate::{
+        Candidate, LinkKind,
+        testing::{TechniqueTester, restrict},
+    };
+
+    /// Removes `digit` from every cell of `house` except `keep`.
+    fn confine(grid: &mut CandidateGrid, house: House, digit: Digit, keep: &[Position]) {
+        for pos in house.cells() {
+            if !keep.contains(&pos) {
+                grid.remove_candidate(pos, digit);
+            }
+        }
+    }
+
+    /// Three conjugate pairs of D4 joined by weak links:
+    /// (0, 0) = (6, 0) - (7, 1) = (7, 5) - (3, 5) = (3, 8).
+    fn x_chain_grid() -> CandidateGrid {
+        let mut grid = CandidateGrid::new();
+        let d = Digit::D4;
+        confine(
+            &mut grid,
+            House::Row { y: 0 },
+            d,
+            &[Position::new(0, 0), Position::new(6, 0)],
+        );
+        confine(
+            &mut grid,
+            House::Column { x: 7 },
+            d,
+            &[Position::new(7, 1), Position::new(7, 5)],
+        );
+        confine(
+            &mut grid,
+            House::Row { y: 5 },
+            d,
+            &[Position::new(7, 5), Position::new(3, 5)],
+        );
+        confine(
+            &mut grid,
+            House::Column { x: 3 },
+            d,
+            &[Position::new(3, 5), Position::new(3, 8)],
+        );
+        grid
+    }
+
+    #[test]
+    fn test_x_chain() {
+        // (0, 0) or (3, 8) holds D4, so (0, 8), which sees both, loses it
+        TechniqueTester::new(x_chain_grid())
+            .apply_once(&XChain::default())
+            .assert_removed_exact(Position::new(0, 8), [Digit::D4])
+            .assert_no_change(Position::new(1, 1));
+    }
+
+    #[test]
+    fn test_x_chain_step_records_links() {
+        let mut grid = x_chain_grid();
+        let step = XChain::default().apply(&mut grid).unwrap().unwrap();
+        assert_eq!(
+            step.eliminations(),
+            [Candidate::new(Position::new(0, 8), Digit::D4)]
+        );
+        let links = step.links();
+        // The chain starts and ends with a strong link, and alternates in between
+        assert_eq!(links.len() % 2, 1);
+        for (i, link) in links.iter().enumerate() {
+            let kind = if i % 2 == 0 {
+                LinkKind::Strong
+            } else {
+                LinkKind::Weak
+            };
+            assert_eq!(link.kind(), kind);
+        }
+        for pair in links.windows(2) {
+            assert_eq!(pair[0].to(), pair[1].from());
+        }
+        assert!(step.cells().contains(links[0].from().pos()));
+    }
+
+    #[test]
+    fn test_x_chain_ignores_houses_with_placed_digit() {
+        // D4 is placed in row 0 but not removed from its peers. (0, 0) and
+        // (6, 0) are the only undecided cells of the row with D4, but both are
+        // false, so linking them would eliminate the placed D4
+        let mut grid = x_chain_grid();
+        confine(
+            &mut grid,
+            House::Row { y: 0 },
+            Digit::D4,
+            &[
+                Position::new(0, 0),
+                Position::new(2, 0),
+                Position::new(6, 0),
+            ],
+        );
+        grid.place(Position::new(2, 0), Digit::D4);
+        TechniqueTester::new(grid)
+            .apply_once(&XChain::default())
+            .assert_no_change(Position::new(2, 0))
+            .assert_no_change(Position::new(0, 8));
+    }
+
+    #[test]
+    fn test_x_chain_respects_max_length() {
+        TechniqueTester::new(x_chain_grid())
+            .apply_once(&XChain::new(4))
+            .assert_no_change(Position::new(0, 8));
+    }
+
+    #[test]
+    fn test_xy_chain() {
+        // (0, 0){1,2} - (0, 4){2,3} - (5, 4){3,4} - (5, 0){4,1}: one end is D1
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(0, 4), &[Digit::D2, Digit::D3]);
+        restrict(&mut grid, Position::new(5, 4), &[Digit::D3, Digit::D4]);
+        restrict(&mut grid, Position::new(5, 0), &[Digit::D4, Digit::D1]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&XyChain::default())
+            .assert_removed_exact(Position::new(3, 0), [Digit::D1])
+            .assert_removed_exact(Position::new(8, 0), [Digit::D1])
+            .assert_no_change(Position::new(0, 1))
+            .assert_no_change(Position::new(5, 1));
+    }
+
+    #[test]
+    fn test_aic_with_ends_in_same_cell() {
+        // (4, 4)D1 = (4, 0)D1 - (4, 0)D2 = (0, 0)D2 - ... = (4, 4)D2:
+        // (4, 4) holds D1 or D2, so its other candidates are removed
+        let mut grid = CandidateGrid::new();
+        confine(
+            &mut grid,
+            House::Column { x: 4 },
+            Digit::D1,
+            &[Position::new(4, 4), Position::new(4, 0)],
+        );
+        restrict(&mut grid, Position::new(4, 0), &[Digit::D1, Digit::D2]);
+        confine(
+            &mut grid,
+            House::Row { y: 0 },
+            Digit::D2,
+            &[Position::new(4, 0), Position::new(0, 0)],
+        );
+        confine(
+            &mut grid,
+            House::Column { x: 0 },
+            Digit::D2,
+            &[Position::new(0, 0), Position::new(0, 4)],
+        );
+        confine(
+            &mut grid,
+            House::Row { y: 4 },
+            Digit::D2,
+            &[Position::new(0, 4), Position::new(4, 4)],
+        );
+
+        TechniqueTester::new(grid)
+            .apply_once(&Aic::default())
+            .assert_removed_exact(
+                Position::new(4, 4),
+                [
+                    Digit::D3,
+                    Digit::D4,
+                    Digit::D5,
+                    Digit::D6,
+                    Digit::D7,
+                    Digit::D8,
+                    Digit::D9,
+                ],
+            );
+    }
+
+    #[test]
+    fn test_discontinuous_loop_places_candidate() {
+        // If (0, 0) is not D1: (0, 0) = D2, (0, 5) = D3, (6, 5) = D1, so (6, 0) is
+        // not D1 and the row 0 conjugate pair forces D1 back into (0, 0)
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(0, 5), &[Digit::D2, Digit::D3]);
+        restrict(&mut grid, Position::new(6, 5), &[Digit::D3, Digit::D1]);
+        confine(
+            &mut grid,
+            House::Row { y: 0 },
+            Digit::D1,
+            &[Position::new(0, 0), Position::new(6, 0)],
+        );
+
+        TechniqueTester::new(grid)
+            .apply_once(&Aic::default())
+            .assert_placed(Position::new(0, 0), Digit::D1);
+    }
+
+    #[test]
+    fn test_no_change_without_strong_links() {
+        TechniqueTester::new(CandidateGrid::new())
+            .apply_once(&Aic::default())
+            .assert_no_change(Position::new(0, 0))
+            .assert_no_change(Position::new(4, 4));
+    }
+}
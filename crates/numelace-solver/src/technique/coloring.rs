@@ -0,0 +1,380 @@
+use numelace_core::{
+    CandidateGrid, Digit, DigitPositions, House, Position, containers::Array81,
+    index::PositionSemantics,
+};
+
+use super::{BoxedTechnique, elimination::DigitElimination};
+use crate::{SolverError, Step, technique::Technique};
+
+/// A technique that two-colors a digit's conjugate-pair chains to find contradictions.
+///
+/// For each digit, cells connected by conjugate pairs (houses in which the digit
+/// has exactly two candidates) form clusters. Within a cluster, the cells are
+/// colored alternately with two colors, exactly one of which is true. Two rules
+/// are applied to each cluster:
+///
+/// - **Color wrap**: if two cells of the same color see each other, that color
+///   is false, and the digit is removed from every cell of that color.
+/// - **Color trap**: an uncolored cell that sees cells of both colors cannot
+///   hold the digit.
+///
+/// Only the first cluster that yields eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{SimpleColoring, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = SimpleColoring::new();
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+///
+/// # See Also
+///
+/// - [`MultiColoring`] - Combines two clusters of the same digit
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SimpleColoring;
+
+impl SimpleColoring {
+    /// Creates a new `SimpleColoring` technique.
+    #[must_use]
+    pub const fn new() -> Self {
+        SimpleColoring
+    }
+}
+
+impl Technique for SimpleColoring {
+    fn name(&self) -> &'static str {
+        "simple coloring"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        for digit in Digit::ALL {
+            let digit_positions = grid.digit_positions(digit);
+            for cluster in color_clusters(grid, digit) {
+                if let Some(targets) = cluster.find_simple(digit_positions) {
+                    let elimination = DigitElimination {
+                        digit,
+                        targets,
+                        cells: cluster.cells(),
+                        houses: vec![],
+                    };
+                    return Ok(elimination.apply(self.name(), grid));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// A technique that combines two coloring clusters of the same digit.
+///
+/// Given two clusters built as in [`SimpleColoring`], with colors `A1`/`B1` and
+/// `A2`/`B2`:
+///
+/// - If a cell of `A1` sees a cell of `A2`, then `A1` and `A2` cannot both be
+///   true, so `B1` or `B2` is true. Any cell that sees both a `B1` cell and a
+///   `B2` cell cannot hold the digit.
+/// - If `A1` has cells that see cells of both `A2` and `B2`, then `A1` is false,
+///   and the digit is removed from every cell of `A1`.
+///
+/// Only the first pair of clusters that yields eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{MultiColoring, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = MultiColoring::new();
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MultiColoring;
+
+impl MultiColoring {
+    /// Creates a new `MultiColoring` technique.
+    #[must_use]
+    pub const fn new() -> Self {
+        MultiColoring
+    }
+}
+
+impl Technique for MultiColoring {
+    fn name(&self) -> &'static str {
+        "multi-coloring"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        for digit in Digit::ALL {
+            let digit_positions = grid.digit_positions(digit);
+            let clusters = color_clusters(grid, digit);
+            for (i, cluster1) in clusters.iter().enumerate() {
+                for cluster2 in &clusters[i + 1..] {
+                    if let Some(targets) = cluster1.find_multi(cluster2, digit_positions) {
+                        let elimination = DigitElimination {
+                            digit,
+                            targets,
+                            cells: cluster1.cells() | cluster2.cells(),
+                            houses: vec![],
+                        };
+                        return Ok(elimination.apply(self.name(), grid));
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// A connected component of a digit's conjugate-pair graph, split into its two colors.
+#[derive(Debug, Clone, Copy)]
+struct Cluster {
+    colors: [DigitPositions; 2],
+}
+
+impl Cluster {
+    /// Returns all cells of the cluster.
+    fn cells(&self) -> DigitPositions {
+        self.colors[0] | self.colors[1]
+    }
+
+    /// Applies the color wrap and color trap rules.
+    fn find_simple(&self, digit_positions: DigitPositions) -> Option<DigitPositions> {
+        for color in self.colors {
+            if !(seen_by_any(color) & color).is_empty() {
+                return Some(color);
+            }
+        }
+        let trapped = seen_by_any(self.colors[0])
+            & seen_by_any(self.colors[1])
+            & digit_positions
+            & !self.cells();
+        (!trapped.is_empty()).then_some(trapped)
+    }
+
+    /// Applies the multi-coloring rules against another cluster of the same digit.
+    fn find_multi(
+        &self,
+        other: &Cluster,
+        digit_positions: DigitPositions,
+    ) -> Option<DigitPositions> {
+        for (color, opposite) in [(0, 1), (1, 0)] {
+            let seen = seen_by_any(self.colors[color]);
+            for (other_color, other_opposite) in [(0, 1), (1, 0)] {
+                if seen.is_disjoint(other.colors[other_color]) {
+                    continue;
+                }
+                // This color sees both colors of the other cluster: it is false
+                if !seen.is_disjoint(other.colors[other_opposite]) {
+                    return Some(self.colors[color]);
+                }
+                let eliminations = seen_by_any(self.colors[opposite])
+                    & seen_by_any(other.colors[other_opposite])
+                    & digit_positions
+                    & !self.cells()
+                    & !other.cells();
+                if !eliminations.is_empty() {
+                    return Some(eliminations);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Builds the coloring clusters of `digit` from its conjugate pairs.
+fn color_clusters(grid: &CandidateGrid, digit: Digit) -> Vec<Cluster> {
+    let mut links = Array81::<DigitPositions, PositionSemantics>::default();
+    let mut linked = DigitPositions::EMPTY;
+    for house in House::ALL {
+        let mask = grid.house_mask(house, digit);
+        if mask.len() != 2 {
+            continue;
+        }
+        let mut ends = mask.iter().map(|i| house.position(i));
+        let (Some(a), Some(b)) = (ends.next(), ends.next()) else {
+            continue;
+        };
+        links[a].insert(b);
+        links[b].insert(a);
+        linked.insert(a);
+        linked.insert(b);
+    }
+
+    let mut clusters = vec![];
+    let mut uncolored = linked;
+    while let Some(start) = uncolored.pop_first() {
+        let mut colors = [DigitPositions::EMPTY; 2];
+        colors[0].insert(start);
+        let mut stack: Vec<(Position, usize)> = vec![(start, 0)];
+        while let Some((pos, color)) = stack.pop() {
+            for next in links[pos] & uncolored {
+                uncolored.remove(next);
+                colors[1 - color].insert(next);
+                stack.push((next, 1 - color));
+            }
+        }
+        clusters.push(Cluster { colors });
+    }
+    clusters
+}
+
+/// Returns the cells that see at least one cell of `cells`.
+fn seen_by_any(cells: DigitPositions) -> DigitPositions {
+    cells
+        .iter()
+        .fold(DigitPositions::EMPTY, |acc, pos| acc | pos.house_peers())
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, Position};
+
+    use super::*;
+    use crate::testing::TechniqueTester;
+
+    /// Removes `digit` from every cell of `house` except `keep`.
+    fn confine(grid: &mut CandidateGrid, house: House, digit: Digit, keep: &[Position]) {
+        for pos in house.cells() {
+            if !keep.contains(&pos) {
+                grid.remove_candidate(pos, digit);
+            }
+        }
+    }
+
+    #[test]
+    fn test_color_trap() {
+        // Chain (0, 0) -row- (6, 0) -col- (6, 5) -row- (2, 5): (0, 0)/(6, 5) vs (6, 0)/(2, 5)
+        let mut grid = CandidateGrid::new();
+        let d = Digit::D7;
+        confine(
+            &mut grid,
+            House::Row { y: 0 },
+            d,
+            &[Position::new(0, 0), Position::new(6, 0)],
+        );
+        confine(
+            &mut grid,
+            House::Column { x: 6 },
+            d,
+            &[Position::new(6, 0), Position::new(6, 5)],
+        );
+        confine(
+            &mut grid,
+            House::Row { y: 5 },
+            d,
+            &[Position::new(6, 5), Position::new(2, 5)],
+        );
+
+        // (0, 0) and (2, 5) have opposite colors, so cells seeing both lose D7
+        TechniqueTester::new(grid)
+            .apply_once(&SimpleColoring::new())
+            .assert_removed_exact(Position::new(2, 1), [d])
+            .assert_removed_exact(Position::new(0, 3), [d])
+            .assert_no_change(Position::new(1, 1))
+            .assert_no_change(Position::new(4, 4));
+    }
+
+    #[test]
+    fn test_color_wrap() {
+        // Chain (0, 0) -row- (4, 0) -col- (4, 4) -row- (1, 4) -col- (1, 1):
+        // (0, 0) and (1, 1) get the same color and share box 0
+        let mut grid = CandidateGrid::new();
+        let d = Digit::D3;
+        confine(
+            &mut grid,
+            House::Row { y: 0 },
+            d,
+            &[Position::new(0, 0), Position::new(4, 0)],
+        );
+        confine(
+            &mut grid,
+            House::Column { x: 4 },
+            d,
+            &[Position::new(4, 0), Position::new(4, 4)],
+        );
+        confine(
+            &mut grid,
+            House::Row { y: 4 },
+            d,
+            &[Position::new(4, 4), Position::new(1, 4)],
+        );
+        confine(
+            &mut grid,
+            House::Column { x: 1 },
+            d,
+            &[Position::new(1, 4), Position::new(1, 1)],
+        );
+
+        TechniqueTester::new(grid)
+            .apply_once(&SimpleColoring::new())
+            .assert_removed_exact(Position::new(0, 0), [d])
+            .assert_removed_exact(Position::new(4, 4), [d])
+            .assert_removed_exact(Position::new(1, 1), [d])
+            .assert_no_change(Position::new(4, 0))
+            .assert_no_change(Position::new(1, 4));
+    }
+
+    #[test]
+    fn test_multi_coloring() {
+        // Cluster 1: (0, 0) -row- (8, 0); cluster 2: (0, 4) -row- (6, 4)
+        let mut grid = CandidateGrid::new();
+        let d = Digit::D5;
+        confine(
+            &mut grid,
+            House::Row { y: 0 },
+            d,
+            &[Position::new(0, 0), Position::new(8, 0)],
+        );
+        confine(
+            &mut grid,
+            House::Row { y: 4 },
+            d,
+            &[Position::new(0, 4), Position::new(6, 4)],
+        );
+
+        // (0, 0) and (0, 4) see each other, so (8, 0) or (6, 4) is true,
+        // and cells seeing both of them lose D5
+        TechniqueTester::new(grid)
+            .apply_once(&MultiColoring::new())
+            .assert_removed_exact(Position::new(6, 1), [d])
+            .assert_removed_exact(Position::new(8, 3), [d])
+            .assert_no_change(Position::new(7, 1))
+            .assert_no_change(Position::new(0, 8));
+    }
+
+    #[test]
+    fn test_no_change_with_single_conjugate_pair() {
+        let mut grid = CandidateGrid::new();
+        let d = Digit::D5;
+        confine(
+            &mut grid,
+            House::Row { y: 0 },
+            d,
+            &[Position::new(0, 0), Position::new(8, 0)],
+        );
+
+        TechniqueTester::new(grid)
+            .apply_once(&SimpleColoring::new())
+            .assert_no_change(Position::new(0, 1))
+            .assert_no_change(Position::new(8, 1));
+    }
+}
@@ -0,0 +1,53 @@
+//! Helpers for enumerating combinations of small sets.
+
+use numelace_core::{containers::BitSet9, index::Index9Semantics};
+
+/// Returns an iterator over all subsets of `set` that contain exactly `size` elements.
+///
+/// Subsets are yielded in ascending order of their raw bits, so the result is
+/// deterministic. Only the submasks of `set` are visited, so small sets are cheap
+/// to enumerate.
+pub(crate) fn subsets_of_size<S>(set: BitSet9<S>, size: usize) -> impl Iterator<Item = BitSet9<S>>
+where
+    S: Index9Semantics,
+{
+    subsets(set).filter(move |sub| sub.len() == size)
+}
+
+/// Returns an iterator over all subsets of `set`, including the empty set and `set` itself.
+///
+/// Subsets are yielded in ascending order of their raw bits.
+pub(crate) fn subsets<S>(set: BitSet9<S>) -> impl Iterator<Item = BitSet9<S>>
+where
+    S: Index9Semantics,
+{
+    let bits = set.bits();
+    // `(sub - bits) & bits` steps to the next larger submask of `bits`, wrapping to 0 after `bits`
+    std::iter::successors(Some(0u16), move |&sub| {
+        (sub != bits).then(|| sub.wrapping_sub(bits) & bits)
+    })
+    .map(BitSet9::from_bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::HouseMask;
+
+    use super::*;
+
+    #[test]
+    fn test_subsets_of_size() {
+        let set = HouseMask::from_iter([1, 3, 4, 8]);
+
+        let pairs: Vec<_> = subsets_of_size(set, 2).collect();
+        assert_eq!(pairs.len(), 6);
+        for pair in &pairs {
+            assert_eq!(pair.len(), 2);
+            assert!(pair.is_subset(set));
+        }
+
+        assert_eq!(subsets_of_size(set, 4).collect::<Vec<_>>(), vec![set]);
+        assert_eq!(subsets_of_size(set, 5).count(), 0);
+        assert_eq!(subsets_of_size(HouseMask::FULL, 3).count(), 84);
+    }
+}
@@ -0,0 +1,28 @@
+//! Single-digit eliminations found by techniques.
+
+use numelace_core::{CandidateGrid, Digit, DigitPositions, House};
+
+use crate::Step;
+
+/// Eliminations of a single digit, with the pattern that justifies them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DigitElimination {
+    pub(crate) digit: Digit,
+    /// The cells the digit is removed from.
+    pub(crate) targets: DigitPositions,
+    /// The cells forming the pattern.
+    pub(crate) cells: DigitPositions,
+    /// The houses the pattern is built on.
+    pub(crate) houses: Vec<House>,
+}
+
+impl DigitElimination {
+    /// Removes the digit from the targets, returning the step of `technique` if
+    /// the grid changed.
+    pub(crate) fn apply(self, technique: &'static str, grid: &mut CandidateGrid) -> Option<Step> {
+        let before = grid.clone();
+        grid.remove_candidate_with_mask(self.targets, self.digit);
+        Step::from_changes(technique, &before, grid)
+            .map(|step| step.with_cells(self.cells).with_houses(self.houses))
+    }
+}
@@ -0,0 +1,200 @@
+use numelace_core::{CandidateGrid, Digit, DigitPositions, House, Position};
+
+use super::{BoxedTechnique, elimination::DigitElimination, links::conjugate_pair};
+use crate::{SolverError, Step, technique::Technique};
+
+/// A technique that combines a box whose candidates form a cross with a conjugate pair.
+///
+/// In an Empty Rectangle, a digit's candidates within a box all lie in one row
+/// and one column of the box (the *cross*), leaving the remaining four cells of
+/// the box empty. Suppose a column outside the box has a conjugate pair on the
+/// digit with one end in the cross row. If that end is true, the box's digit
+/// must be in the cross column; otherwise the other end is true. Either way, the
+/// cell in the cross column and the other end's row cannot hold the digit. The
+/// same applies with rows and columns swapped.
+///
+/// Only the first empty rectangle that yields an elimination is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{EmptyRectangle, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = EmptyRectangle::new();
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EmptyRectangle;
+
+impl EmptyRectangle {
+    /// Creates a new `EmptyRectangle` technique.
+    #[must_use]
+    pub const fn new() -> Self {
+        EmptyRectangle
+    }
+}
+
+impl Technique for EmptyRectangle {
+    fn name(&self) -> &'static str {
+        "empty rectangle"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        for digit in Digit::ALL {
+            if let Some(elimination) = find_empty_rectangle(grid, digit) {
+                return Ok(elimination.apply(self.name(), grid));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Finds an empty rectangle for `digit`.
+fn find_empty_rectangle(grid: &CandidateGrid, digit: Digit) -> Option<DigitElimination> {
+    let digit_positions = grid.digit_positions(digit);
+    for box_index in 0..9 {
+        let box_positions = DigitPositions::BOX_POSITIONS[box_index];
+        let box_cells = digit_positions & box_positions;
+        if box_cells.len() < 2 {
+            continue;
+        }
+        let origin = Position::box_origin(box_index);
+        for y in origin.y()..origin.y() + 3 {
+            for x in origin.x()..origin.x() + 3 {
+                let row = DigitPositions::ROW_POSITIONS[y];
+                let col = DigitPositions::COLUMN_POSITIONS[x];
+                // Candidates must occupy both arms of the cross, not just one line
+                if !box_cells.is_subset(row | col)
+                    || box_cells.is_subset(row)
+                    || box_cells.is_subset(col)
+                {
+                    continue;
+                }
+                if let Some((target, link)) = find_target(digit_positions, box_positions, x, y) {
+                    return Some(DigitElimination {
+                        digit,
+                        targets: DigitPositions::from_iter([target]),
+                        cells: box_cells | (link.positions() & digit_positions),
+                        houses: vec![House::Box { index: box_index }, link],
+                    });
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Finds a conjugate pair linked to the cross at row `y` and column `x`,
+/// returning the position the digit can be eliminated from and the house of the pair.
+fn find_target(
+    digit_positions: DigitPositions,
+    box_positions: DigitPositions,
+    x: u8,
+    y: u8,
+) -> Option<(Position, House)> {
+    // A column pair with one end in the cross row eliminates from the cross column
+    for link_x in 0..9 {
+        let Some(ends) = conjugate_pair(digit_positions, House::Column { x: link_x }) else {
+            continue;
+        };
+        for (near, far) in [(ends[0], ends[1]), (ends[1], ends[0])] {
+            let target = Position::new(x, far.y());
+            if near.y() == y
+                && !box_positions.contains(near)
+                && !box_positions.contains(target)
+                && digit_positions.contains(target)
+            {
+                return Some((target, House::Column { x: link_x }));
+            }
+        }
+    }
+    // A row pair with one end in the cross column eliminates from the cross row
+    for link_y in 0..9 {
+        let Some(ends) = conjugate_pair(digit_positions, House::Row { y: link_y }) else {
+            continue;
+        };
+        for (near, far) in [(ends[0], ends[1]), (ends[1], ends[0])] {
+            let target = Position::new(far.x(), y);
+            if near.x() == x
+                && !box_positions.contains(near)
+                && !box_positions.contains(target)
+                && digit_positions.contains(target)
+            {
+                return Some((target, House::Row { y: link_y }));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, Position};
+
+    use super::*;
+    use crate::testing::TechniqueTester;
+
+    /// Leaves D2 in box 4 only on the cross through (4, 4).
+    fn empty_rectangle_in_box_4() -> CandidateGrid {
+        let mut grid = CandidateGrid::new();
+        for pos in Position::BOXES[4] {
+            if pos.x() != 4 && pos.y() != 4 {
+                grid.remove_candidate(pos, Digit::D2);
+            }
+        }
+        grid
+    }
+
+    #[test]
+    fn test_empty_rectangle_with_column_link() {
+        // Column 7 pair (7, 4)-(7, 8): (4, 8) is eliminated
+        let mut grid = empty_rectangle_in_box_4();
+        for y in 0..9 {
+            if y != 4 && y != 8 {
+                grid.remove_candidate(Position::new(7, y), Digit::D2);
+            }
+        }
+
+        TechniqueTester::new(grid)
+            .apply_once(&EmptyRectangle::new())
+            .assert_removed_exact(Position::new(4, 8), [Digit::D2])
+            .assert_no_change(Position::new(4, 0))
+            .assert_no_change(Position::new(3, 8));
+    }
+
+    #[test]
+    fn test_empty_rectangle_with_row_link() {
+        // Row 1 pair (4, 1)-(0, 1): (0, 4) is eliminated
+        let mut grid = empty_rectangle_in_box_4();
+        for x in 0..9 {
+            if x != 0 && x != 4 {
+                grid.remove_candidate(Position::new(x, 1), Digit::D2);
+            }
+        }
+
+        TechniqueTester::new(grid)
+            .apply_once(&EmptyRectangle::new())
+            .assert_removed_exact(Position::new(0, 4), [Digit::D2])
+            .assert_no_change(Position::new(8, 4))
+            .assert_no_change(Position::new(0, 3));
+    }
+
+    #[test]
+    fn test_no_change_without_link() {
+        let grid = empty_rectangle_in_box_4();
+
+        TechniqueTester::new(grid)
+            .apply_once(&EmptyRectangle::new())
+            .assert_no_change(Position::new(4, 0))
+            .assert_no_change(Position::new(0, 4));
+    }
+}
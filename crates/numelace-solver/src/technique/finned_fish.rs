@@ -0,0 +1,325 @@
+use numelace_core::{
+    CandidateGrid, Digit, DigitPositions, HouseMask, containers::Array9, index::CellIndexSemantics,
+};
+
+use super::{
+    BoxedTechnique,
+    basic_fish::{Orientation, lines_positions},
+    combinations::subsets_of_size,
+    elimination::DigitElimination,
+};
+use crate::{SolverError, Step, technique::Technique};
+
+/// A technique that finds a basic fish with extra candidates (fins) confined to one box.
+///
+/// A finned fish is an X-Wing, Swordfish, or Jellyfish whose base lines contain
+/// a few additional candidates outside the cover lines. If those *fins* all lie
+/// in a single box, then either a fin is true or the underlying fish holds. In
+/// both cases the digit is eliminated from cover-line cells that lie inside the
+/// fin's box (and outside the base lines), since those cells see every fin.
+///
+/// Two variants are distinguished:
+///
+/// - **Finned**: every base line keeps at least two candidates in the cover lines,
+///   so removing the fins leaves a complete basic fish.
+/// - **Sashimi**: some base line keeps only one candidate in the cover lines, so
+///   the fish would be degenerate without the fins.
+///
+/// The fish size is a parameter (2: X-Wing, 3: Swordfish, 4: Jellyfish), and each
+/// size and variant reports a distinct [`name`](Technique::name).
+///
+/// Only the first fish that yields eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{FinnedFish, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = FinnedFish::sashimi(3);
+/// assert_eq!(technique.name(), "sashimi swordfish");
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FinnedFish {
+    size: usize,
+    sashimi: bool,
+}
+
+impl FinnedFish {
+    /// Creates a new finned fish technique with the given number of base lines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is not in the range 2-4.
+    #[must_use]
+    pub const fn finned(size: usize) -> Self {
+        assert!(size >= 2 && size <= 4);
+        Self {
+            size,
+            sashimi: false,
+        }
+    }
+
+    /// Creates a new sashimi fish technique with the given number of base lines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is not in the range 2-4.
+    #[must_use]
+    pub const fn sashimi(size: usize) -> Self {
+        assert!(size >= 2 && size <= 4);
+        Self {
+            size,
+            sashimi: true,
+        }
+    }
+
+    /// Returns the number of base (and cover) lines.
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if this is the sashimi variant.
+    #[must_use]
+    pub const fn is_sashimi(&self) -> bool {
+        self.sashimi
+    }
+}
+
+impl Technique for FinnedFish {
+    fn name(&self) -> &'static str {
+        match (self.sashimi, self.size) {
+            (false, 2) => "finned x-wing",
+            (false, 3) => "finned swordfish",
+            (false, 4) => "finned jellyfish",
+            (true, 2) => "sashimi x-wing",
+            (true, 3) => "sashimi swordfish",
+            (true, 4) => "sashimi jellyfish",
+            _ => unreachable!(),
+        }
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        for digit in Digit::ALL {
+            for orientation in [Orientation::Rows, Orientation::Columns] {
+                if let Some(elimination) = self.find(grid, digit, orientation) {
+                    return Ok(elimination.apply(self.name(), grid));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Cover line indices within each band of three lines.
+const BAND_MASKS: [HouseMask; 3] = [
+    HouseMask::from_bits(0b000_000_111),
+    HouseMask::from_bits(0b000_111_000),
+    HouseMask::from_bits(0b111_000_000),
+];
+
+impl FinnedFish {
+    /// Finds a finned fish for `digit` with base lines in the given orientation.
+    fn find(
+        self,
+        grid: &CandidateGrid,
+        digit: Digit,
+        orientation: Orientation,
+    ) -> Option<DigitElimination> {
+        let digit_positions = grid.digit_positions(digit);
+        let masks = Array9::<HouseMask, CellIndexSemantics>::from_fn(|line| {
+            grid.house_mask(orientation.base(line), digit)
+        });
+
+        // Each base line holds at most `size` cover candidates plus fins from one box segment
+        let mut base_candidates = HouseMask::new();
+        for line in 0..9 {
+            if (2..=self.size + 3).contains(&masks[line].len()) {
+                base_candidates.insert(line);
+            }
+        }
+
+        for base in subsets_of_size(base_candidates, self.size) {
+            for (base_band, base_band_mask) in (0..).zip(BAND_MASKS) {
+                if base.is_disjoint(base_band_mask) {
+                    continue;
+                }
+                for (cover_band, cover_band_mask) in (0..).zip(BAND_MASKS) {
+                    // Every base candidate outside the fin box must be covered
+                    let mut required = HouseMask::new();
+                    let mut optional = HouseMask::new();
+                    for line in base {
+                        if base_band_mask.contains(line) {
+                            required |= masks[line] & !cover_band_mask;
+                            optional |= masks[line] & cover_band_mask;
+                        } else {
+                            required |= masks[line];
+                        }
+                    }
+                    if optional.is_empty() || required.len() > self.size {
+                        continue;
+                    }
+                    optional &= !required;
+
+                    let box_index = orientation.box_index(base_band, cover_band);
+                    for extra_len in 0..=(self.size - required.len()).min(optional.len()) {
+                        for extra in subsets_of_size(optional, extra_len) {
+                            let cover = required | extra;
+                            if !self.matches(&masks, base, cover) {
+                                continue;
+                            }
+                            let base_positions =
+                                lines_positions(base, |line| orientation.base(line));
+                            let cover_positions =
+                                lines_positions(cover, |line| orientation.cover(line));
+                            let eliminations = cover_positions
+                                & DigitPositions::BOX_POSITIONS[box_index]
+                                & !base_positions
+                                & digit_positions;
+                            if !eliminations.is_empty() {
+                                let houses = base
+                                    .iter()
+                                    .map(|line| orientation.base(line))
+                                    .chain(cover.iter().map(|line| orientation.cover(line)))
+                                    .collect();
+                                return Some(DigitElimination {
+                                    digit,
+                                    targets: eliminations,
+                                    cells: base_positions & digit_positions,
+                                    houses,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns `true` if `base` and `cover` form a fish of this variant with at least one fin.
+    fn matches(
+        self,
+        masks: &Array9<HouseMask, CellIndexSemantics>,
+        base: HouseMask,
+        cover: HouseMask,
+    ) -> bool {
+        let mut has_fin = false;
+        let mut sashimi = false;
+        for line in base {
+            let mask = masks[line];
+            match (mask & cover).len() {
+                0 => return false,
+                1 => sashimi = true,
+                _ => {}
+            }
+            has_fin |= !(mask & !cover).is_empty();
+        }
+        has_fin && sashimi == self.sashimi
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, Position};
+
+    use super::*;
+    use crate::{technique::BasicFish, testing::TechniqueTester};
+
+    /// Removes `digit` from every cell of the given rows except the given columns.
+    fn confine_rows(grid: &mut CandidateGrid, digit: Digit, rows: &[u8], cols: &[u8]) {
+        for &y in rows {
+            for x in 0..9 {
+                if !cols.contains(&x) {
+                    grid.remove_candidate(Position::new(x, y), digit);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_finned_x_wing() {
+        // D1 in rows 1 and 7 lies in columns 1 and 6, with a fin at (8, 7) in box 8
+        let mut grid = CandidateGrid::new();
+        confine_rows(&mut grid, Digit::D1, &[1], &[1, 6]);
+        confine_rows(&mut grid, Digit::D1, &[7], &[1, 6, 8]);
+
+        TechniqueTester::new(grid.clone())
+            .apply_once(&BasicFish::new(2))
+            .assert_no_change(Position::new(6, 6));
+
+        TechniqueTester::new(grid)
+            .apply_once(&FinnedFish::finned(2))
+            .assert_removed_exact(Position::new(6, 6), [Digit::D1])
+            .assert_removed_exact(Position::new(6, 8), [Digit::D1])
+            .assert_no_change(Position::new(6, 0))
+            .assert_no_change(Position::new(1, 6))
+            .assert_no_change(Position::new(7, 6));
+    }
+
+    #[test]
+    fn test_sashimi_x_wing() {
+        // Row 7 has only (6, 7) in the cover columns; (7, 7) and (8, 7) are fins
+        let mut grid = CandidateGrid::new();
+        confine_rows(&mut grid, Digit::D1, &[1], &[1, 6]);
+        confine_rows(&mut grid, Digit::D1, &[7], &[6, 7, 8]);
+
+        TechniqueTester::new(grid.clone())
+            .apply_once(&FinnedFish::finned(2))
+            .assert_no_change(Position::new(6, 6));
+
+        TechniqueTester::new(grid)
+            .apply_once(&FinnedFish::sashimi(2))
+            .assert_removed_exact(Position::new(6, 6), [Digit::D1])
+            .assert_removed_exact(Position::new(6, 8), [Digit::D1])
+            .assert_no_change(Position::new(6, 0))
+            .assert_no_change(Position::new(1, 6));
+    }
+
+    #[test]
+    fn test_finned_swordfish_in_columns() {
+        // D5 in columns 0, 4, 8 lies in rows 0, 4, 8, with a fin at (8, 6) in box 8
+        let mut grid = CandidateGrid::new();
+        let mut confine_col = |x: u8, rows: &[u8]| {
+            for y in 0..9 {
+                if !rows.contains(&y) {
+                    grid.remove_candidate(Position::new(x, y), Digit::D5);
+                }
+            }
+        };
+        confine_col(0, &[0, 4]);
+        confine_col(4, &[0, 4, 8]);
+        confine_col(8, &[4, 6, 8]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&FinnedFish::finned(3))
+            .assert_removed_exact(Position::new(6, 8), [Digit::D5])
+            .assert_removed_exact(Position::new(7, 8), [Digit::D5])
+            .assert_no_change(Position::new(1, 8))
+            .assert_no_change(Position::new(8, 8));
+    }
+
+    #[test]
+    fn test_no_change_when_fins_span_boxes() {
+        // Fins at (4, 7) and (8, 7) are in different boxes
+        let mut grid = CandidateGrid::new();
+        confine_rows(&mut grid, Digit::D1, &[1], &[1, 6]);
+        confine_rows(&mut grid, Digit::D1, &[7], &[1, 4, 6, 8]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&FinnedFish::finned(2))
+            .assert_no_change(Position::new(6, 6))
+            .assert_no_change(Position::new(1, 8))
+            .assert_no_change(Position::new(4, 8));
+    }
+}
@@ -0,0 +1,467 @@
+use std::collections::{HashMap, hash_map::Entry};
+
+use numelace_core::{
+    CandidateGrid, Digit, DigitPositions, House, Position, containers::Array9,
+    index::DigitSemantics,
+};
+
+use super::BoxedTechnique;
+use crate::{SolverError, Step, TechniqueSolver, TechniqueSolverStats, technique::Technique};
+
+/// The default maximum number of solver steps taken to follow an assumption.
+pub const DEFAULT_MAX_TRIAL_STEPS: usize = 64;
+
+/// A technique that assumes a candidate and removes it if that leads to a contradiction.
+///
+/// The candidate is placed on a copy of the grid, and the inner
+/// [`TechniqueSolver`] follows the consequences for at most `max_steps` steps. If
+/// a cell loses all its candidates, a digit is placed twice in a house, or a
+/// house has no room left for a digit, the assumption was false and the
+/// candidate is removed.
+///
+/// Unlike [`BacktrackSolver`](crate::BacktrackSolver), the assumption is never
+/// kept: every deduction is justified by the chain of steps that disproves it.
+/// By default, the consequences are followed with the fundamental techniques
+/// (see [`fundamental_techniques`](super::fundamental_techniques)).
+///
+/// Only the first candidate that leads to a contradiction is removed.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{Nishio, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = Nishio::default();
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Nishio {
+    solver: TechniqueSolver,
+    max_steps: usize,
+}
+
+impl Nishio {
+    /// Creates a new `Nishio` technique that follows assumptions with `solver`
+    /// for at most `max_steps` steps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_steps` is 0.
+    #[must_use]
+    pub fn new(solver: TechniqueSolver, max_steps: usize) -> Self {
+        assert!(max_steps >= 1);
+        Self { solver, max_steps }
+    }
+
+    /// Returns the maximum number of solver steps taken to follow an assumption.
+    #[must_use]
+    pub const fn max_steps(&self) -> usize {
+        self.max_steps
+    }
+}
+
+impl Default for Nishio {
+    fn default() -> Self {
+        Self::new(
+            TechniqueSolver::with_fundamental_techniques(),
+            DEFAULT_MAX_TRIAL_STEPS,
+        )
+    }
+}
+
+impl Technique for Nishio {
+    fn name(&self) -> &'static str {
+        "nishio"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(self.clone())
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        let snapshot = grid.clone();
+        let mut trials = Trials::new(&self.solver, self.max_steps, &snapshot);
+        for pos in !snapshot.decided_cells() {
+            for digit in snapshot.candidates_at(pos) {
+                if trials.assume(pos, digit)?.is_none() {
+                    grid.remove_candidate(pos, digit);
+                    return Ok(Step::from_changes(self.name(), &snapshot, grid)
+                        .map(|step| step.with_cells([pos].into_iter().collect())));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// A technique that follows every candidate of a cell and keeps their common consequences.
+///
+/// Each candidate of the cell is assumed in turn on a copy of the grid, and the
+/// inner [`TechniqueSolver`] follows the consequences for at most `max_steps`
+/// steps (see [`Nishio`]). One of the candidates is true, so a candidate removed
+/// by every assumption that does not lead to a contradiction is removed from the
+/// grid. A digit placed by every such assumption is placed as well, since its
+/// cell loses all its other candidates.
+///
+/// Cells with fewer candidates are tried first. Only the first cell that yields
+/// eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{CellForcingChain, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = CellForcingChain::default();
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct CellForcingChain {
+    solver: TechniqueSolver,
+    max_steps: usize,
+}
+
+impl CellForcingChain {
+    /// Creates a new `CellForcingChain` technique that follows assumptions with
+    /// `solver` for at most `max_steps` steps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_steps` is 0.
+    #[must_use]
+    pub fn new(solver: TechniqueSolver, max_steps: usize) -> Self {
+        assert!(max_steps >= 1);
+        Self { solver, max_steps }
+    }
+
+    /// Returns the maximum number of solver steps taken to follow an assumption.
+    #[must_use]
+    pub const fn max_steps(&self) -> usize {
+        self.max_steps
+    }
+}
+
+impl Default for CellForcingChain {
+    fn default() -> Self {
+        Self::new(
+            TechniqueSolver::with_fundamental_techniques(),
+            DEFAULT_MAX_TRIAL_STEPS,
+        )
+    }
+}
+
+impl Technique for CellForcingChain {
+    fn name(&self) -> &'static str {
+        "cell forcing chain"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(self.clone())
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        let snapshot = grid.clone();
+        let mut trials = Trials::new(&self.solver, self.max_steps, &snapshot);
+        let cells = snapshot.classify_cells::<10>();
+        for pos in cells[2..].iter().flat_map(|cells| cells.iter()) {
+            let branches = snapshot
+                .candidates_at(pos)
+                .into_iter()
+                .map(|digit| (pos, digit));
+            if let Some(step) = trials.conclude(self.name(), grid, branches)? {
+                return Ok(Some(step.with_cells([pos].into_iter().collect())));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// A technique that follows every position of a digit in a house and keeps their
+/// common consequences.
+///
+/// Each position of the digit in the house is assumed in turn on a copy of the
+/// grid, and the inner [`TechniqueSolver`] follows the consequences for at most
+/// `max_steps` steps (see [`Nishio`]). The digit is placed in one of the
+/// positions, so a candidate removed by every assumption that does not lead to a
+/// contradiction is removed from the grid.
+///
+/// Only the first house and digit that yield eliminations are applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{DigitForcingChain, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = DigitForcingChain::default();
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct DigitForcingChain {
+    solver: TechniqueSolver,
+    max_steps: usize,
+}
+
+impl DigitForcingChain {
+    /// Creates a new `DigitForcingChain` technique that follows assumptions with
+    /// `solver` for at most `max_steps` steps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_steps` is 0.
+    #[must_use]
+    pub fn new(solver: TechniqueSolver, max_steps: usize) -> Self {
+        assert!(max_steps >= 1);
+        Self { solver, max_steps }
+    }
+
+    /// Returns the maximum number of solver steps taken to follow an assumption.
+    #[must_use]
+    pub const fn max_steps(&self) -> usize {
+        self.max_steps
+    }
+}
+
+impl Default for DigitForcingChain {
+    fn default() -> Self {
+        Self::new(
+            TechniqueSolver::with_fundamental_techniques(),
+            DEFAULT_MAX_TRIAL_STEPS,
+        )
+    }
+}
+
+impl Technique for DigitForcingChain {
+    fn name(&self) -> &'static str {
+        "digit forcing chain"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(self.clone())
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        let snapshot = grid.clone();
+        let mut trials = Trials::new(&self.solver, self.max_steps, &snapshot);
+        for house in House::ALL {
+            for digit in Digit::ALL {
+                let positions = snapshot.digit_positions(digit) & house.positions();
+                if positions.len() < 2 {
+                    continue;
+                }
+                let branches = positions.iter().map(|pos| (pos, digit));
+                if let Some(step) = trials.conclude(self.name(), grid, branches)? {
+                    return Ok(Some(step.with_cells(positions).with_houses([house])));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Assumptions followed on copies of a grid, with their outcomes cached.
+struct Trials<'a> {
+    solver: &'a TechniqueSolver,
+    max_steps: usize,
+    grid: &'a CandidateGrid,
+    /// The grid reached from each assumption, or `None` on a contradiction.
+    outcomes: HashMap<(Position, Digit), Option<CandidateGrid>>,
+}
+
+impl<'a> Trials<'a> {
+    fn new(solver: &'a TechniqueSolver, max_steps: usize, grid: &'a CandidateGrid) -> Self {
+        Self {
+            solver,
+            max_steps,
+            grid,
+            outcomes: HashMap::new(),
+        }
+    }
+
+    /// Places `digit` at `pos` and follows the consequences.
+    ///
+    /// Returns the grid reached, or `None` if the assumption leads to a contradiction.
+    fn assume(
+        &mut self,
+        pos: Position,
+        digit: Digit,
+    ) -> Result<Option<&CandidateGrid>, SolverError> {
+        let outcome = match self.outcomes.entry((pos, digit)) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                entry.insert(follow(self.solver, self.max_steps, self.grid, pos, digit)?)
+            }
+        };
+        Ok(outcome.as_ref())
+    }
+
+    /// Follows each branch, one of which is known to be true, and removes from
+    /// `grid` the candidates that no consistent branch keeps.
+    ///
+    /// Returns the step of `technique` if any candidate was removed. If every
+    /// branch leads to a contradiction, the grid itself is contradictory and
+    /// nothing is removed.
+    fn conclude(
+        &mut self,
+        technique: &'static str,
+        grid: &mut CandidateGrid,
+        branches: impl IntoIterator<Item = (Position, Digit)>,
+    ) -> Result<Option<Step>, SolverError> {
+        let mut kept = Array9::<DigitPositions, DigitSemantics>::default();
+        let mut survived = false;
+        for (pos, digit) in branches {
+            if let Some(outcome) = self.assume(pos, digit)? {
+                survived = true;
+                for digit in Digit::ALL {
+                    kept[digit] |= outcome.digit_positions(digit);
+                }
+            }
+        }
+        if !survived {
+            return Ok(None);
+        }
+        for digit in Digit::ALL {
+            let targets = self.grid.digit_positions(digit) & !kept[digit];
+            grid.remove_candidate_with_mask(targets, digit);
+        }
+        Ok(Step::from_changes(technique, self.grid, grid))
+    }
+}
+
+/// Places `digit` at `pos` on a copy of `grid` and applies `solver` for at most
+/// `max_steps` steps.
+///
+/// Returns the grid reached, or `None` if it becomes contradictory. An
+/// interruption of the solver is passed on, since it says nothing about the
+/// assumption.
+fn follow(
+    solver: &TechniqueSolver,
+    max_steps: usize,
+    grid: &CandidateGrid,
+    pos: Position,
+    digit: Digit,
+) -> Result<Option<CandidateGrid>, SolverError> {
+    let mut grid = grid.clone();
+    grid.place(pos, digit);
+    let mut stats = TechniqueSolverStats::new();
+    for _ in 0..max_steps {
+        match solver.step(&mut grid, &mut stats) {
+            Ok(Some(_)) => {}
+            Ok(None) => break,
+            Err(SolverError::Inconsistent(_)) => return Ok(None),
+            Err(err) => return Err(err),
+        }
+    }
+    Ok((!is_contradictory(&grid)).then_some(grid))
+}
+
+/// Returns `true` if a cell has no candidates, a digit is decided twice in a
+/// house, or a house has no candidate left for a digit.
+fn is_contradictory(grid: &CandidateGrid) -> bool {
+    grid.check_consistency().is_err()
+        || House::ALL.into_iter().any(|house| {
+            Digit::ALL
+                .into_iter()
+                .any(|digit| grid.house_mask(house, digit).is_empty())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, Position};
+
+    use super::*;
+    use crate::testing::{TechniqueTester, restrict};
+
+    #[test]
+    fn test_nishio() {
+        // Assuming D1 at (0, 0) forces D3 at (5, 0) and D4 at (0, 3), which leaves
+        // (5, 3){3, 4} empty
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(5, 0), &[Digit::D1, Digit::D3]);
+        restrict(&mut grid, Position::new(5, 3), &[Digit::D3, Digit::D4]);
+        restrict(&mut grid, Position::new(0, 3), &[Digit::D4, Digit::D1]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&Nishio::default())
+            .assert_removed_exact(Position::new(0, 0), [Digit::D1])
+            .assert_no_change(Position::new(5, 0))
+            .assert_no_change(Position::new(5, 3));
+    }
+
+    #[test]
+    fn test_nishio_no_change_without_contradiction() {
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&Nishio::default())
+            .assert_no_change(Position::new(0, 0))
+            .assert_no_change(Position::new(1, 0));
+    }
+
+    #[test]
+    fn test_cell_forcing_chain() {
+        // (0, 0) = D1 forces D3 at (4, 0), and (0, 0) = D2 forces D3 at (0, 4):
+        // either way, (4, 4) loses D3
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(4, 0), &[Digit::D1, Digit::D3]);
+        restrict(&mut grid, Position::new(0, 4), &[Digit::D2, Digit::D3]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&CellForcingChain::default())
+            .assert_removed_exact(Position::new(4, 4), [Digit::D3])
+            .assert_no_change(Position::new(4, 0))
+            .assert_no_change(Position::new(8, 8));
+    }
+
+    #[test]
+    fn test_cell_forcing_chain_no_change_when_every_branch_contradicts() {
+        // (0, 0) = D1 leaves D3 for both (1, 0) and (2, 0), and (0, 0) = D2 leaves
+        // D4 for both (0, 1) and (1, 1)
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(1, 0), &[Digit::D1, Digit::D3]);
+        restrict(&mut grid, Position::new(2, 0), &[Digit::D1, Digit::D3]);
+        restrict(&mut grid, Position::new(0, 1), &[Digit::D2, Digit::D4]);
+        restrict(&mut grid, Position::new(1, 1), &[Digit::D2, Digit::D4]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&CellForcingChain::default())
+            .assert_no_change(Position::new(0, 0))
+            .assert_no_change(Position::new(4, 4))
+            .assert_no_change(Position::new(8, 8));
+    }
+
+    #[test]
+    fn test_digit_forcing_chain() {
+        // D1 in row 0 is at (0, 0) or (4, 0). Either way, (0, 4){1, 5} and
+        // (4, 4){1, 5} end up holding D1 and D5, which leave the rest of row 4
+        let mut grid = CandidateGrid::new();
+        for x in (1..9).filter(|&x| x != 4) {
+            grid.remove_candidate(Position::new(x, 0), Digit::D1);
+        }
+        restrict(&mut grid, Position::new(0, 4), &[Digit::D1, Digit::D5]);
+        restrict(&mut grid, Position::new(4, 4), &[Digit::D1, Digit::D5]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&DigitForcingChain::default())
+            .assert_removed_includes(Position::new(8, 4), [Digit::D1, Digit::D5])
+            .assert_no_change(Position::new(8, 8));
+    }
+}
@@ -1,7 +1,7 @@
-use numelace_core::{CandidateGrid, Digit, Position};
+use numelace_core::{CandidateGrid, Digit, House, Position};
 
 use super::BoxedTechnique;
-use crate::{SolverError, technique::Technique};
+use crate::{Candidate, SolverError, Step, technique::Technique};
 
 /// A technique that finds digits that can only go in one position within a house.
 ///
@@ -20,7 +20,7 @@ use crate::{SolverError, technique::Technique};
 /// let technique = HiddenSingle::new();
 ///
 /// // Apply the technique
-/// let changed = technique.apply(&mut grid)?;
+/// let step = technique.apply(&mut grid)?;
 /// # Ok::<(), numelace_solver::SolverError>(())
 /// ```
 #[derive(Debug, Default, Clone, Copy)]
@@ -43,15 +43,22 @@ impl Technique for HiddenSingle {
         Box::new(*self)
     }
 
-    fn apply(&self, grid: &mut CandidateGrid) -> Result<bool, SolverError> {
-        let mut changed = false;
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        let before = grid.clone();
+        let mut placements = vec![];
+        // Houses in which a digit was placed
+        let mut houses = vec![];
 
         for digit in Digit::ALL {
             for y in 0..9 {
                 let row = grid.row_mask(y, digit);
                 if row.len() == 1 {
                     let x = row.first().unwrap();
-                    changed |= grid.place(Position::new(x, y), digit);
+                    let pos = Position::new(x, y);
+                    if grid.place(pos, digit) {
+                        placements.push(Candidate::new(pos, digit));
+                        houses.push(House::Row { y });
+                    }
                 }
             }
 
@@ -59,7 +66,11 @@ impl Technique for HiddenSingle {
                 let col = grid.col_mask(x, digit);
                 if col.len() == 1 {
                     let y = col.first().unwrap();
-                    changed |= grid.place(Position::new(x, y), digit);
+                    let pos = Position::new(x, y);
+                    if grid.place(pos, digit) {
+                        placements.push(Candidate::new(pos, digit));
+                        houses.push(House::Column { x });
+                    }
                 }
             }
 
@@ -67,12 +78,19 @@ impl Technique for HiddenSingle {
                 let block = grid.box_mask(box_index, digit);
                 if block.len() == 1 {
                     let i = block.first().unwrap();
-                    changed |= grid.place(Position::from_box(box_index, i), digit);
+                    let pos = Position::from_box(box_index, i);
+                    if grid.place(pos, digit) {
+                        placements.push(Candidate::new(pos, digit));
+                        houses.push(House::Box { index: box_index });
+                    }
                 }
             }
         }
 
-        Ok(changed)
+        Ok(
+            Step::from_placements(self.name(), &before, grid, placements)
+                .map(|step| step.with_houses(houses)),
+        )
     }
 }
 
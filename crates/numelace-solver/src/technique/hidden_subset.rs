@@ -0,0 +1,220 @@
+use numelace_core::{
+    CandidateGrid, Digit, DigitPositions, DigitSet, House, HouseMask, containers::Array9,
+    index::DigitSemantics,
+};
+
+use super::{BoxedTechnique, combinations::subsets_of_size};
+use crate::{SolverError, Step, technique::Technique};
+
+/// A technique that finds N digits in a house whose candidates are confined to the same N cells.
+///
+/// When N digits within a row, column, or box can only be placed in the same N
+/// cells (a "hidden pair", "hidden triple", or "hidden quad"), those cells must
+/// hold exactly those digits. All other candidates are therefore removed from
+/// those cells.
+///
+/// The subset size is a parameter, so one implementation covers pairs (2),
+/// triples (3), and quads (4). Each size reports a distinct [`name`](Technique::name)
+/// so that solver statistics count them separately.
+///
+/// Only the first subset that yields eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{HiddenSubset, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = HiddenSubset::new(3);
+/// assert_eq!(technique.name(), "hidden triples");
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct HiddenSubset {
+    size: usize,
+}
+
+impl HiddenSubset {
+    /// Creates a new `HiddenSubset` technique for subsets of the given size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is not in the range 2-4.
+    #[must_use]
+    pub const fn new(size: usize) -> Self {
+        assert!(size >= 2 && size <= 4);
+        Self { size }
+    }
+
+    /// Returns the number of digits (and cells) in the subset.
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl Technique for HiddenSubset {
+    fn name(&self) -> &'static str {
+        match self.size {
+            2 => "hidden pairs",
+            3 => "hidden triples",
+            4 => "hidden quads",
+            _ => unreachable!(),
+        }
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        for house in House::ALL {
+            let masks =
+                Array9::<HouseMask, DigitSemantics>::from_fn(|digit| grid.house_mask(house, digit));
+
+            // Digits already placed in the house (or with too many cells) cannot be part of the subset
+            let mut eligible = DigitSet::new();
+            for digit in Digit::ALL {
+                if (2..=self.size).contains(&masks[digit].len()) {
+                    eligible.insert(digit);
+                }
+            }
+
+            for subset in subsets_of_size(eligible, self.size) {
+                let cells = subset
+                    .iter()
+                    .fold(HouseMask::EMPTY, |acc, digit| acc | masks[digit]);
+                if cells.len() != self.size {
+                    continue;
+                }
+
+                let positions: DigitPositions = cells.iter().map(|i| house.position(i)).collect();
+                let before = grid.clone();
+                for digit in Digit::ALL {
+                    if !subset.contains(digit) {
+                        grid.remove_candidate_with_mask(positions, digit);
+                    }
+                }
+                if let Some(step) = Step::from_changes(self.name(), &before, grid) {
+                    return Ok(Some(step.with_cells(positions).with_houses([house])));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, Position};
+
+    use super::*;
+    use crate::testing::TechniqueTester;
+
+    /// Removes `digit` from every cell of `house` except `keep`.
+    fn confine(grid: &mut CandidateGrid, house: House, digit: Digit, keep: &[Position]) {
+        for pos in house.cells() {
+            if !keep.contains(&pos) {
+                grid.remove_candidate(pos, digit);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hidden_pair_in_row() {
+        // D1 and D2 in row 0 can only go in (2, 0) and (6, 0)
+        let mut grid = CandidateGrid::new();
+        let cells = [Position::new(2, 0), Position::new(6, 0)];
+        confine(&mut grid, House::Row { y: 0 }, Digit::D1, &cells);
+        confine(&mut grid, House::Row { y: 0 }, Digit::D2, &cells);
+
+        let others = [
+            Digit::D3,
+            Digit::D4,
+            Digit::D5,
+            Digit::D6,
+            Digit::D7,
+            Digit::D8,
+            Digit::D9,
+        ];
+        TechniqueTester::new(grid)
+            .apply_once(&HiddenSubset::new(2))
+            .assert_removed_exact(Position::new(2, 0), others)
+            .assert_removed_exact(Position::new(6, 0), others)
+            .assert_no_change(Position::new(3, 0))
+            .assert_no_change(Position::new(2, 1));
+    }
+
+    #[test]
+    fn test_hidden_triple_in_box() {
+        // D4, D5, D6 in box 4 are confined to three cells
+        let mut grid = CandidateGrid::new();
+        let house = House::Box { index: 4 };
+        let cells = [
+            Position::new(3, 3),
+            Position::new(4, 4),
+            Position::new(5, 5),
+        ];
+        confine(&mut grid, house, Digit::D4, &cells[..2]);
+        confine(&mut grid, house, Digit::D5, &cells[1..]);
+        confine(&mut grid, house, Digit::D6, &[cells[0], cells[2]]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&HiddenSubset::new(3))
+            .assert_removed_includes(Position::new(3, 3), [Digit::D1, Digit::D9])
+            .assert_removed_includes(Position::new(4, 4), [Digit::D2, Digit::D8])
+            .assert_removed_includes(Position::new(5, 5), [Digit::D3, Digit::D7])
+            .assert_no_change(Position::new(3, 4));
+    }
+
+    #[test]
+    fn test_hidden_quad_in_column() {
+        let mut grid = CandidateGrid::new();
+        let house = House::Column { x: 8 };
+        let cells = [
+            Position::new(8, 0),
+            Position::new(8, 2),
+            Position::new(8, 5),
+            Position::new(8, 7),
+        ];
+        for digit in [Digit::D1, Digit::D3, Digit::D5, Digit::D7] {
+            confine(&mut grid, house, digit, &cells);
+        }
+
+        let others = [Digit::D2, Digit::D4, Digit::D6, Digit::D8, Digit::D9];
+        TechniqueTester::new(grid)
+            .apply_once(&HiddenSubset::new(4))
+            .assert_removed_exact(Position::new(8, 0), others)
+            .assert_removed_exact(Position::new(8, 7), others)
+            .assert_no_change(Position::new(8, 1));
+    }
+
+    #[test]
+    fn test_no_change_when_cells_differ() {
+        // D1 and D2 are confined to two cells each, but not the same two cells
+        let mut grid = CandidateGrid::new();
+        let house = House::Row { y: 0 };
+        confine(
+            &mut grid,
+            house,
+            Digit::D1,
+            &[Position::new(0, 0), Position::new(1, 0)],
+        );
+        confine(
+            &mut grid,
+            house,
+            Digit::D2,
+            &[Position::new(0, 0), Position::new(2, 0)],
+        );
+
+        TechniqueTester::new(grid)
+            .apply_once(&HiddenSubset::new(2))
+            .assert_no_change(Position::new(0, 0))
+            .assert_no_change(Position::new(1, 0))
+            .assert_no_change(Position::new(2, 0));
+    }
+}
@@ -0,0 +1,31 @@
+//! Helpers for finding links between candidates.
+
+use numelace_core::{DigitPositions, House, Position};
+
+/// Returns the two cells of a conjugate pair (strong link) in `house`, if any.
+///
+/// A conjugate pair exists when a digit has exactly two candidates in a house:
+/// if one of them is false, the other must be true. `digit_positions` is the set
+/// of candidate positions for the digit (see
+/// [`CandidateGrid::digit_positions`](numelace_core::CandidateGrid::digit_positions)).
+pub(crate) fn conjugate_pair(
+    digit_positions: DigitPositions,
+    house: House,
+) -> Option<[Position; 2]> {
+    let cells = digit_positions & house.positions();
+    if cells.len() != 2 {
+        return None;
+    }
+    let mut cells = cells.into_iter();
+    Some([cells.next()?, cells.next()?])
+}
+
+/// Returns all conjugate pairs of a digit in the given houses.
+pub(crate) fn conjugate_pairs(
+    digit_positions: DigitPositions,
+    houses: impl IntoIterator<Item = House>,
+) -> impl Iterator<Item = [Position; 2]> {
+    houses
+        .into_iter()
+        .filter_map(move |house| conjugate_pair(digit_positions, house))
+}
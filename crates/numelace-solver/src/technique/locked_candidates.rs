@@ -0,0 +1,296 @@
+use numelace_core::{CandidateGrid, Digit, DigitPositions, House, HouseMask, Position};
+
+use super::{BoxedTechnique, elimination::DigitElimination};
+use crate::{SolverError, Step, technique::Technique};
+
+/// Cell indices within a box, grouped by the row they belong to.
+const BOX_ROW_MASKS: [HouseMask; 3] = [
+    HouseMask::from_bits(0b000_000_111),
+    HouseMask::from_bits(0b000_111_000),
+    HouseMask::from_bits(0b111_000_000),
+];
+
+/// Cell indices within a box, grouped by the column they belong to.
+const BOX_COL_MASKS: [HouseMask; 3] = [
+    HouseMask::from_bits(0b001_001_001),
+    HouseMask::from_bits(0b010_010_010),
+    HouseMask::from_bits(0b100_100_100),
+];
+
+/// Cell indices within a row or column, grouped by the box they belong to.
+const LINE_SEGMENT_MASKS: [HouseMask; 3] = [
+    HouseMask::from_bits(0b000_000_111),
+    HouseMask::from_bits(0b000_111_000),
+    HouseMask::from_bits(0b111_000_000),
+];
+
+/// A technique that finds digits locked into the intersection of a box and a line.
+///
+/// Locked candidates come in two flavors:
+///
+/// - **Pointing** (box → line): all candidates of a digit within a box lie in a
+///   single row or column. The digit must be placed in that intersection, so it
+///   is removed from the rest of the line outside the box.
+/// - **Claiming** (line → box): all candidates of a digit within a row or column
+///   lie in a single box. The digit is removed from the rest of the box.
+///
+/// Only the first intersection that yields eliminations is applied, so each
+/// application corresponds to a single deduction.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{LockedCandidates, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = LockedCandidates::new();
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LockedCandidates;
+
+impl LockedCandidates {
+    /// Creates a new `LockedCandidates` technique.
+    #[must_use]
+    pub const fn new() -> Self {
+        LockedCandidates
+    }
+}
+
+impl Technique for LockedCandidates {
+    fn name(&self) -> &'static str {
+        "locked candidates"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        for digit in Digit::ALL {
+            if let Some(elimination) = find_pointing(grid, digit) {
+                return Ok(elimination.apply(self.name(), grid));
+            }
+        }
+        for digit in Digit::ALL {
+            if let Some(elimination) = find_claiming(grid, digit) {
+                return Ok(elimination.apply(self.name(), grid));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Finds a box whose candidates for `digit` are confined to one row or column.
+fn find_pointing(grid: &CandidateGrid, digit: Digit) -> Option<DigitElimination> {
+    let digit_positions = grid.digit_positions(digit);
+    for box_index in 0..9 {
+        let mask = grid.box_mask(box_index, digit);
+        if mask.len() < 2 {
+            continue;
+        }
+        let origin = Position::box_origin(box_index);
+        let box_positions = DigitPositions::BOX_POSITIONS[box_index];
+        for (offset, row_mask) in (0..).zip(BOX_ROW_MASKS) {
+            if mask.is_subset(row_mask) {
+                let y = origin.y() + offset;
+                let line = DigitPositions::ROW_POSITIONS[y];
+                let eliminations = line & !box_positions & digit_positions;
+                if !eliminations.is_empty() {
+                    return Some(DigitElimination {
+                        digit,
+                        targets: eliminations,
+                        cells: line & box_positions & digit_positions,
+                        houses: vec![House::Box { index: box_index }, House::Row { y }],
+                    });
+                }
+            }
+        }
+        for (offset, col_mask) in (0..).zip(BOX_COL_MASKS) {
+            if mask.is_subset(col_mask) {
+                let x = origin.x() + offset;
+                let line = DigitPositions::COLUMN_POSITIONS[x];
+                let eliminations = line & !box_positions & digit_positions;
+                if !eliminations.is_empty() {
+                    return Some(DigitElimination {
+                        digit,
+                        targets: eliminations,
+                        cells: line & box_positions & digit_positions,
+                        houses: vec![House::Box { index: box_index }, House::Column { x }],
+                    });
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Finds a row or column whose candidates for `digit` are confined to one box.
+fn find_claiming(grid: &CandidateGrid, digit: Digit) -> Option<DigitElimination> {
+    let digit_positions = grid.digit_positions(digit);
+    for y in 0..9 {
+        let mask = grid.row_mask(y, digit);
+        if mask.len() < 2 {
+            continue;
+        }
+        for (segment, segment_mask) in (0..).zip(LINE_SEGMENT_MASKS) {
+            if mask.is_subset(segment_mask) {
+                let box_index = (y / 3) * 3 + segment;
+                let line = DigitPositions::ROW_POSITIONS[y];
+                let box_positions = DigitPositions::BOX_POSITIONS[box_index];
+                let eliminations = box_positions & !line & digit_positions;
+                if !eliminations.is_empty() {
+                    return Some(DigitElimination {
+                        digit,
+                        targets: eliminations,
+                        cells: line & box_positions & digit_positions,
+                        houses: vec![House::Row { y }, House::Box { index: box_index }],
+                    });
+                }
+            }
+        }
+    }
+    for x in 0..9 {
+        let mask = grid.col_mask(x, digit);
+        if mask.len() < 2 {
+            continue;
+        }
+        for (segment, segment_mask) in (0..).zip(LINE_SEGMENT_MASKS) {
+            if mask.is_subset(segment_mask) {
+                let box_index = segment * 3 + x / 3;
+                let line = DigitPositions::COLUMN_POSITIONS[x];
+                let box_positions = DigitPositions::BOX_POSITIONS[box_index];
+                let eliminations = box_positions & !line & digit_positions;
+                if !eliminations.is_empty() {
+                    return Some(DigitElimination {
+                        digit,
+                        targets: eliminations,
+                        cells: line & box_positions & digit_positions,
+                        houses: vec![House::Column { x }, House::Box { index: box_index }],
+                    });
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, Position};
+
+    use super::*;
+    use crate::{Candidate, testing::TechniqueTester};
+
+    #[test]
+    fn test_pointing_in_row() {
+        // D5 in box 0 is confined to row 0, so it is removed from the rest of row 0
+        let mut grid = CandidateGrid::new();
+        for pos in Position::BOXES[0] {
+            if pos.y() != 0 {
+                grid.remove_candidate(pos, Digit::D5);
+            }
+        }
+
+        TechniqueTester::new(grid)
+            .apply_once(&LockedCandidates::new())
+            .assert_removed_exact(Position::new(3, 0), [Digit::D5])
+            .assert_removed_exact(Position::new(8, 0), [Digit::D5])
+            .assert_no_change(Position::new(0, 0))
+            .assert_no_change(Position::new(3, 1));
+    }
+
+    #[test]
+    fn test_pointing_elimination_leaving_single_is_not_a_placement() {
+        // As in test_pointing_in_row, but (3, 0) only has {5, 6} and keeps 6
+        let mut grid = CandidateGrid::new();
+        for pos in Position::BOXES[0] {
+            if pos.y() != 0 {
+                grid.remove_candidate(pos, Digit::D5);
+            }
+        }
+        for digit in Digit::ALL {
+            if !matches!(digit, Digit::D5 | Digit::D6) {
+                grid.remove_candidate(Position::new(3, 0), digit);
+            }
+        }
+
+        let step = LockedCandidates::new().apply(&mut grid).unwrap().unwrap();
+        assert_eq!(grid.candidates_at(Position::new(3, 0)).len(), 1);
+        assert_eq!(step.placements(), []);
+        assert!(
+            step.eliminations()
+                .contains(&Candidate::new(Position::new(3, 0), Digit::D5))
+        );
+        assert_eq!(step.eliminations().len(), 6);
+    }
+
+    #[test]
+    fn test_pointing_in_column() {
+        // D7 in box 4 is confined to column 5, so it is removed from the rest of column 5
+        let mut grid = CandidateGrid::new();
+        for pos in Position::BOXES[4] {
+            if pos.x() != 5 {
+                grid.remove_candidate(pos, Digit::D7);
+            }
+        }
+
+        TechniqueTester::new(grid)
+            .apply_once(&LockedCandidates::new())
+            .assert_removed_exact(Position::new(5, 0), [Digit::D7])
+            .assert_removed_exact(Position::new(5, 8), [Digit::D7])
+            .assert_no_change(Position::new(5, 4))
+            .assert_no_change(Position::new(4, 0));
+    }
+
+    #[test]
+    fn test_claiming_in_row() {
+        // D3 in row 4 is confined to box 3, so it is removed from the rest of box 3
+        let mut grid = CandidateGrid::new();
+        for pos in Position::ROWS[4] {
+            if pos.x() >= 3 {
+                grid.remove_candidate(pos, Digit::D3);
+            }
+        }
+
+        TechniqueTester::new(grid)
+            .apply_once(&LockedCandidates::new())
+            .assert_removed_exact(Position::new(0, 3), [Digit::D3])
+            .assert_removed_exact(Position::new(2, 5), [Digit::D3])
+            .assert_no_change(Position::new(1, 4))
+            .assert_no_change(Position::new(3, 3));
+    }
+
+    #[test]
+    fn test_claiming_in_column() {
+        // D9 in column 7 is confined to box 8, so it is removed from the rest of box 8
+        let mut grid = CandidateGrid::new();
+        for pos in Position::COLUMNS[7] {
+            if pos.y() < 6 {
+                grid.remove_candidate(pos, Digit::D9);
+            }
+        }
+
+        TechniqueTester::new(grid)
+            .apply_once(&LockedCandidates::new())
+            .assert_removed_exact(Position::new(6, 6), [Digit::D9])
+            .assert_removed_exact(Position::new(8, 8), [Digit::D9])
+            .assert_no_change(Position::new(7, 7))
+            .assert_no_change(Position::new(6, 0));
+    }
+
+    #[test]
+    fn test_no_change_when_candidates_spread() {
+        // On a fresh grid every digit spans multiple rows and columns of each box
+        let grid = CandidateGrid::new();
+
+        TechniqueTester::new(grid)
+            .apply_once(&LockedCandidates::new())
+            .assert_no_change(Position::new(0, 0))
+            .assert_no_change(Position::new(4, 4));
+    }
+}
@@ -2,25 +2,148 @@
 //!
 //! This module provides various techniques for solving Sudoku puzzles.
 //! Each technique implements the [`Technique`] trait and can be applied to a candidate grid.
+//! [`TechniqueRegistry`] lists the built-in techniques with their tiers, and
+//! selects technique sets by name or maximum tier.
 
 use std::fmt::Debug;
 
 use numelace_core::CandidateGrid;
 
-pub use self::{hidden_single::HiddenSingle, naked_single::NakedSingle};
-use crate::SolverError;
+pub use self::{
+    als_xy_wing::AlsXyWing,
+    als_xz::AlsXz,
+    basic_fish::BasicFish,
+    bug::BugPlusOne,
+    chains::{Aic, DEFAULT_MAX_CHAIN_LENGTH, XChain, XyChain},
+    coloring::{MultiColoring, SimpleColoring},
+    empty_rectangle::EmptyRectangle,
+    finned_fish::FinnedFish,
+    forcing_chains::{CellForcingChain, DEFAULT_MAX_TRIAL_STEPS, DigitForcingChain, Nishio},
+    hidden_single::HiddenSingle,
+    hidden_subset::HiddenSubset,
+    locked_candidates::LockedCandidates,
+    naked_single::NakedSingle,
+    naked_subset::NakedSubset,
+    registry::{TechniqueInfo, TechniqueRegistry, UnknownTechniqueError},
+    skyscraper::Skyscraper,
+    sue_de_coq::SueDeCoq,
+    two_string_kite::TwoStringKite,
+    unique_rectangle::{UniqueRectangle, UniqueRectangleKind},
+    w_wing::WWing,
+    xy_wing::XyWing,
+    xyz_wing::XyzWing,
+};
+use crate::{SolverError, Step};
 
+mod als;
+mod als_xy_wing;
+mod als_xz;
+mod basic_fish;
+mod bug;
+mod chain;
+mod chains;
+mod coloring;
+mod combinations;
+mod elimination;
+mod empty_rectangle;
+mod finned_fish;
+mod forcing_chains;
 mod hidden_single;
+mod hidden_subset;
+mod links;
+mod locked_candidates;
 mod naked_single;
+mod naked_subset;
+mod registry;
+mod skyscraper;
+mod sue_de_coq;
+mod two_string_kite;
+mod unique_rectangle;
+mod w_wing;
+mod xy_wing;
+mod xyz_wing;
 
 /// Returns all available techniques.
 ///
 /// Techniques are ordered from easiest to hardest.
 /// This list may grow as new techniques are implemented.
+///
+/// The list includes uniqueness techniques (see
+/// [`Technique::requires_unique_solution`]), which
+/// [`TechniqueSolver`](crate::TechniqueSolver) only applies when explicitly enabled.
 #[must_use]
 pub fn all_techniques() -> Vec<BoxedTechnique> {
-    fundamental_techniques()
-    // Future: add more advanced techniques here
+    let mut techniques = fundamental_techniques();
+    techniques.push(Box::new(LockedCandidates::new()));
+    for size in 2..=3 {
+        techniques.push(Box::new(NakedSubset::new(size)));
+        techniques.push(Box::new(HiddenSubset::new(size)));
+    }
+    techniques.push(Box::new(BasicFish::new(2)));
+    techniques.push(Box::new(Skyscraper::new()));
+    techniques.push(Box::new(TwoStringKite::new()));
+    techniques.push(Box::new(EmptyRectangle::new()));
+    techniques.push(Box::new(SimpleColoring::new()));
+    techniques.push(Box::new(XyWing::new()));
+    techniques.push(Box::new(XyzWing::new()));
+    techniques.push(Box::new(WWing::new()));
+    techniques.push(Box::new(BasicFish::new(3)));
+    techniques.push(Box::new(NakedSubset::new(4)));
+    techniques.push(Box::new(HiddenSubset::new(4)));
+    techniques.push(Box::new(BasicFish::new(4)));
+    for kind in [
+        UniqueRectangleKind::Type1,
+        UniqueRectangleKind::Type2,
+        UniqueRectangleKind::Type3,
+        UniqueRectangleKind::Type4,
+        UniqueRectangleKind::Hidden,
+    ] {
+        techniques.push(Box::new(UniqueRectangle::new(kind)));
+    }
+    techniques.push(Box::new(BugPlusOne::new()));
+    techniques.push(Box::new(MultiColoring::new()));
+    for size in 2..=4 {
+        techniques.push(Box::new(FinnedFish::finned(size)));
+        techniques.push(Box::new(FinnedFish::sashimi(size)));
+    }
+    techniques.push(Box::new(SueDeCoq::new()));
+    techniques.push(Box::new(XChain::default()));
+    techniques.push(Box::new(XyChain::default()));
+    techniques.push(Box::new(AlsXz::new()));
+    techniques.push(Box::new(AlsXyWing::new()));
+    techniques.push(Box::new(Aic::default()));
+    techniques
+}
+
+/// Returns the forcing techniques, ordered from easiest to hardest.
+///
+/// These last-resort techniques assume a candidate and follow its consequences
+/// with an inner [`TechniqueSolver`](crate::TechniqueSolver) for a bounded number
+/// of steps:
+/// - **Nishio**: A candidate whose assumption leads to a contradiction
+/// - **Cell Forcing Chain**: Common consequences of every candidate of a cell
+/// - **Digit Forcing Chain**: Common consequences of every position of a digit in a house
+///
+/// Following assumptions is far more expensive than pattern-based techniques,
+/// so these are not part of [`all_techniques`]. Append them to it to solve hard
+/// puzzles without backtracking.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_solver::{TechniqueSolver, technique};
+///
+/// let mut techniques = technique::all_techniques();
+/// techniques.extend(technique::forcing_techniques());
+/// let solver = TechniqueSolver::new(techniques);
+/// ```
+#[must_use]
+pub fn forcing_techniques() -> Vec<BoxedTechnique> {
+    vec![
+        Box::new(Nishio::default()),
+        Box::new(CellForcingChain::default()),
+        Box::new(DigitForcingChain::default()),
+    ]
 }
 
 /// Returns the fundamental techniques.
@@ -58,13 +181,31 @@ pub fn fundamental_techniques() -> Vec<BoxedTechnique> {
 /// A trait representing a Sudoku solving technique.
 ///
 /// Each technique is applied to a candidate grid and updates cell values or candidates.
-pub trait Technique: Debug {
+///
+/// Techniques must be [`Send`] and [`Sync`], so that a
+/// [`TechniqueSolver`](crate::TechniqueSolver) can be shared by the worker
+/// threads of a [`BatchSolver`](crate::BatchSolver).
+pub trait Technique: Debug + Send + Sync {
     /// Returns the name of the technique.
     fn name(&self) -> &'static str;
 
     /// Returns a boxed clone of the technique.
     fn clone_box(&self) -> BoxedTechnique;
 
+    /// Returns `true` if the technique is only valid for puzzles with a unique solution.
+    ///
+    /// Uniqueness techniques (such as Unique Rectangles) derive eliminations from
+    /// the assumption that the puzzle cannot have multiple solutions. Applying
+    /// them to a puzzle with several solutions may remove valid candidates, so
+    /// [`TechniqueSolver`](crate::TechniqueSolver) skips them unless explicitly
+    /// enabled with
+    /// [`assume_unique_solution`](crate::TechniqueSolver::assume_unique_solution).
+    ///
+    /// The default implementation returns `false`.
+    fn requires_unique_solution(&self) -> bool {
+        false
+    }
+
     /// Applies the technique to a candidate grid.
     ///
     /// # Arguments
@@ -73,13 +214,14 @@ pub trait Technique: Debug {
     ///
     /// # Returns
     ///
-    /// * `Ok(true)` - The technique was applied and the grid was updated
-    /// * `Ok(false)` - The technique was applied but the grid was not updated
+    /// * `Ok(Some(step))` - The technique was applied and the grid was updated;
+    ///   `step` describes the changes and the pattern that justifies them
+    /// * `Ok(None)` - The technique was applied but the grid was not updated
     ///
     /// # Errors
     ///
     /// Returns an error if the technique detects an invalid state in the grid.
-    fn apply(&self, grid: &mut CandidateGrid) -> Result<bool, SolverError>;
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError>;
 }
 
 /// A boxed technique.
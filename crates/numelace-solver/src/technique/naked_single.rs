@@ -1,7 +1,7 @@
 use numelace_core::{CandidateGrid, Digit, DigitPositions};
 
 use super::BoxedTechnique;
-use crate::{SolverError, technique::Technique};
+use crate::{SolverError, Step, technique::Technique};
 
 /// A technique that finds cells with only one remaining candidate and propagates constraints.
 ///
@@ -24,7 +24,7 @@ use crate::{SolverError, technique::Technique};
 /// let technique = NakedSingle::new();
 ///
 /// // Apply the technique
-/// let changed = technique.apply(&mut grid)?;
+/// let step = technique.apply(&mut grid)?;
 /// # Ok::<(), numelace_solver::SolverError>(())
 /// ```
 #[derive(Debug, Default, Clone, Copy)]
@@ -47,8 +47,10 @@ impl Technique for NakedSingle {
         Box::new(*self)
     }
 
-    fn apply(&self, grid: &mut CandidateGrid) -> Result<bool, SolverError> {
-        let mut changed = false;
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        let before = grid.clone();
+        // Decided cells whose digit was removed from a peer
+        let mut sources = DigitPositions::EMPTY;
 
         let decided_cells = grid.decided_cells();
         for digit in Digit::ALL {
@@ -57,11 +59,13 @@ impl Technique for NakedSingle {
                     | DigitPositions::COLUMN_POSITIONS[pos.x()]
                     | DigitPositions::BOX_POSITIONS[pos.box_index()];
                 affected_pos.remove(pos);
-                changed |= grid.remove_candidate_with_mask(affected_pos, digit);
+                if grid.remove_candidate_with_mask(affected_pos, digit) {
+                    sources.insert(pos);
+                }
             }
         }
 
-        Ok(changed)
+        Ok(Step::from_changes(self.name(), &before, grid).map(|step| step.with_cells(sources)))
     }
 }
 
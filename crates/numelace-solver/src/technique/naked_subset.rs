@@ -0,0 +1,205 @@
+use numelace_core::{
+    CandidateGrid, DigitPositions, DigitSet, House, HouseMask, containers::Array9,
+    index::CellIndexSemantics,
+};
+
+use super::{BoxedTechnique, combinations::subsets_of_size};
+use crate::{SolverError, Step, technique::Technique};
+
+/// A technique that finds N cells in a house whose candidates are limited to the same N digits.
+///
+/// When N cells within a row, column, or box together contain only N distinct
+/// candidates (a "naked pair", "naked triple", or "naked quad"), those digits must
+/// occupy exactly those cells. The digits are therefore removed from all other
+/// cells in the house.
+///
+/// The subset size is a parameter, so one implementation covers pairs (2),
+/// triples (3), and quads (4). Each size reports a distinct [`name`](Technique::name)
+/// so that solver statistics count them separately.
+///
+/// Only the first subset that yields eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{NakedSubset, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = NakedSubset::new(2);
+/// assert_eq!(technique.name(), "naked pairs");
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct NakedSubset {
+    size: usize,
+}
+
+impl NakedSubset {
+    /// Creates a new `NakedSubset` technique for subsets of the given size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is not in the range 2-4.
+    #[must_use]
+    pub const fn new(size: usize) -> Self {
+        assert!(size >= 2 && size <= 4);
+        Self { size }
+    }
+
+    /// Returns the number of cells (and digits) in the subset.
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl Technique for NakedSubset {
+    fn name(&self) -> &'static str {
+        match self.size {
+            2 => "naked pairs",
+            3 => "naked triples",
+            4 => "naked quads",
+            _ => unreachable!(),
+        }
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        for house in House::ALL {
+            let cells = house.cells();
+            let candidates = Array9::<DigitSet, CellIndexSemantics>::from_fn(|i| {
+                grid.candidates_at(cells[usize::from(i)])
+            });
+
+            // Only undecided cells with at most `size` candidates can be part of the subset
+            let mut eligible = HouseMask::new();
+            for i in 0..9 {
+                if (2..=self.size).contains(&candidates[i].len()) {
+                    eligible.insert(i);
+                }
+            }
+
+            for subset in subsets_of_size(eligible, self.size) {
+                let digits = subset
+                    .iter()
+                    .fold(DigitSet::EMPTY, |acc, i| acc | candidates[i]);
+                if digits.len() != self.size {
+                    continue;
+                }
+
+                let subset_positions: DigitPositions =
+                    subset.iter().map(|i| house.position(i)).collect();
+                let others = house.positions() & !subset_positions;
+                let before = grid.clone();
+                for digit in digits {
+                    grid.remove_candidate_with_mask(others, digit);
+                }
+                if let Some(step) = Step::from_changes(self.name(), &before, grid) {
+                    return Ok(Some(step.with_cells(subset_positions).with_houses([house])));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, House, Position};
+
+    use super::*;
+    use crate::testing::{TechniqueTester, restrict};
+
+    #[test]
+    fn test_naked_pair_in_row() {
+        // (0, 0) and (4, 0) both contain only {1, 2}
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(4, 0), &[Digit::D1, Digit::D2]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&NakedSubset::new(2))
+            .assert_removed_exact(Position::new(1, 0), [Digit::D1, Digit::D2])
+            .assert_removed_exact(Position::new(8, 0), [Digit::D1, Digit::D2])
+            .assert_no_change(Position::new(0, 0))
+            .assert_no_change(Position::new(4, 0))
+            .assert_no_change(Position::new(0, 1));
+    }
+
+    #[test]
+    fn test_naked_pair_step_records_pattern() {
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(4, 0), &[Digit::D1, Digit::D2]);
+
+        let step = NakedSubset::new(2).apply(&mut grid).unwrap().unwrap();
+        assert_eq!(step.technique(), "naked pairs");
+        assert_eq!(
+            step.cells().iter().collect::<Vec<_>>(),
+            [Position::new(0, 0), Position::new(4, 0)]
+        );
+        assert_eq!(step.houses(), [House::Row { y: 0 }]);
+        // D1 and D2 are removed from the seven other cells of row 0
+        assert_eq!(step.eliminations().len(), 14);
+        assert_eq!(step.placements(), []);
+    }
+
+    #[test]
+    fn test_naked_triple_in_column() {
+        // {1, 2}, {2, 3}, {1, 3} in column 3 form a naked triple
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(3, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(3, 4), &[Digit::D2, Digit::D3]);
+        restrict(&mut grid, Position::new(3, 8), &[Digit::D1, Digit::D3]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&NakedSubset::new(3))
+            .assert_removed_exact(Position::new(3, 1), [Digit::D1, Digit::D2, Digit::D3])
+            .assert_removed_exact(Position::new(3, 7), [Digit::D1, Digit::D2, Digit::D3])
+            .assert_no_change(Position::new(3, 4))
+            .assert_no_change(Position::new(4, 1));
+    }
+
+    #[test]
+    fn test_naked_quad_in_box() {
+        let mut grid = CandidateGrid::new();
+        let digits = [Digit::D4, Digit::D5, Digit::D6, Digit::D7];
+        restrict(&mut grid, Position::new(6, 6), &digits[..2]);
+        restrict(&mut grid, Position::new(7, 6), &digits[1..3]);
+        restrict(&mut grid, Position::new(8, 7), &digits[2..]);
+        restrict(&mut grid, Position::new(6, 8), &[Digit::D4, Digit::D7]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&NakedSubset::new(4))
+            .assert_removed_exact(Position::new(7, 7), digits)
+            .assert_removed_exact(Position::new(8, 8), digits)
+            .assert_no_change(Position::new(6, 6))
+            .assert_no_change(Position::new(0, 6));
+    }
+
+    #[test]
+    fn test_no_change_when_union_too_large() {
+        // {1, 2} and {1, 3} do not form a naked pair
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(4, 0), &[Digit::D1, Digit::D3]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&NakedSubset::new(2))
+            .assert_no_change(Position::new(1, 0))
+            .assert_no_change(Position::new(8, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn test_new_rejects_invalid_size() {
+        let _ = NakedSubset::new(5);
+    }
+}
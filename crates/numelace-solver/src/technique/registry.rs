@@ -0,0 +1,276 @@
+//! Lookup of techniques by name and difficulty tier.
+
+use crate::{DifficultyTier, technique_weight};
+
+use super::{BoxedTechnique, all_techniques, forcing_techniques};
+
+/// A registered technique, with the metadata used to select it.
+#[derive(Debug, Clone)]
+pub struct TechniqueInfo {
+    technique: BoxedTechnique,
+    tier: DifficultyTier,
+}
+
+impl TechniqueInfo {
+    fn new(technique: BoxedTechnique) -> Self {
+        let tier = DifficultyTier::from_weight(technique_weight(technique.name()));
+        Self { technique, tier }
+    }
+
+    /// Returns the name of the technique.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.technique.name()
+    }
+
+    /// Returns the difficulty tier of the technique.
+    ///
+    /// The tier follows from the technique's weight, see
+    /// [`technique_weight`](crate::technique_weight).
+    #[must_use]
+    pub fn tier(&self) -> DifficultyTier {
+        self.tier
+    }
+
+    /// Returns `true` if the technique is only valid for puzzles with a unique
+    /// solution.
+    ///
+    /// See [`Technique::requires_unique_solution`](super::Technique::requires_unique_solution).
+    #[must_use]
+    pub fn requires_unique_solution(&self) -> bool {
+        self.technique.requires_unique_solution()
+    }
+
+    /// Returns a new instance of the technique.
+    #[must_use]
+    pub fn technique(&self) -> BoxedTechnique {
+        self.technique.clone()
+    }
+}
+
+/// A list of techniques that can be selected by name or by tier.
+///
+/// The built-in registry holds [`all_techniques`] followed by
+/// [`forcing_techniques`], in that order, which is by increasing weight.
+/// Selections keep the registry order, whatever the order of the names asked
+/// for, so that a solver built from the built-in registry still tries easier
+/// techniques first. Other registries keep the order they were given.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_solver::{
+///     DifficultyTier, TechniqueSolver,
+///     technique::TechniqueRegistry,
+/// };
+///
+/// let registry = TechniqueRegistry::builtin();
+/// let x_wing = registry.get("x-wing").unwrap();
+/// assert_eq!(x_wing.tier(), DifficultyTier::Hard);
+/// assert!(!x_wing.requires_unique_solution());
+///
+/// let techniques = registry.select(["x-wing", "hidden singles"])?;
+/// let names: Vec<_> = techniques.iter().map(|technique| technique.name()).collect();
+/// assert_eq!(names, ["hidden singles", "x-wing"]);
+///
+/// let solver = TechniqueSolver::new(registry.up_to_tier(DifficultyTier::Medium));
+/// # Ok::<(), numelace_solver::technique::UnknownTechniqueError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct TechniqueRegistry {
+    entries: Vec<TechniqueInfo>,
+}
+
+impl TechniqueRegistry {
+    /// Creates a registry of every built-in technique.
+    #[must_use]
+    pub fn builtin() -> Self {
+        let mut techniques = all_techniques();
+        techniques.extend(forcing_techniques());
+        Self::from_techniques(techniques)
+    }
+
+    /// Creates a registry of the given techniques, in order.
+    ///
+    /// Custom techniques are rated like in
+    /// [`technique_weight`](crate::technique_weight).
+    #[must_use]
+    pub fn from_techniques(techniques: Vec<BoxedTechnique>) -> Self {
+        Self {
+            entries: techniques.into_iter().map(TechniqueInfo::new).collect(),
+        }
+    }
+
+    /// Adds a technique at the end of the registry.
+    pub fn register(&mut self, technique: BoxedTechnique) {
+        self.entries.push(TechniqueInfo::new(technique));
+    }
+
+    /// Returns the registered techniques, in order.
+    #[must_use]
+    pub fn entries(&self) -> &[TechniqueInfo] {
+        &self.entries
+    }
+
+    /// Returns the technique named `name`, if registered.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&TechniqueInfo> {
+        self.entries.iter().find(|entry| entry.name() == name)
+    }
+
+    /// Returns the techniques named in `names`, in registry order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnknownTechniqueError`] for the first name that is not
+    /// registered.
+    pub fn select<I, S>(&self, names: I) -> Result<Vec<BoxedTechnique>, UnknownTechniqueError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut selected = vec![false; self.entries.len()];
+        for name in names {
+            let name = name.as_ref();
+            let index = self
+                .entries
+                .iter()
+                .position(|entry| entry.name() == name)
+                .ok_or_else(|| UnknownTechniqueError {
+                    name: name.to_owned(),
+                })?;
+            selected[index] = true;
+        }
+        Ok(self
+            .entries
+            .iter()
+            .zip(selected)
+            .filter(|(_, selected)| *selected)
+            .map(|(entry, _)| entry.technique())
+            .collect())
+    }
+
+    /// Returns the techniques of tier `max_tier` or easier, in registry order.
+    ///
+    /// The forcing techniques are [`Extreme`](DifficultyTier::Extreme), so they
+    /// are only included for that tier.
+    #[must_use]
+    pub fn up_to_tier(&self, max_tier: DifficultyTier) -> Vec<BoxedTechnique> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.tier() <= max_tier)
+            .map(TechniqueInfo::technique)
+            .collect()
+    }
+}
+
+impl Default for TechniqueRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+/// Error returned when selecting a technique that is not registered.
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+#[display("unknown technique '{name}'")]
+pub struct UnknownTechniqueError {
+    #[error(not(source))]
+    name: String,
+}
+
+impl UnknownTechniqueError {
+    /// Returns the name that is not registered.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::technique::fundamental_techniques;
+
+    fn names(techniques: &[BoxedTechnique]) -> Vec<&'static str> {
+        techniques
+            .iter()
+            .map(|technique| technique.name())
+            .collect()
+    }
+
+    #[test]
+    fn test_builtin_names_are_unique() {
+        let registry = TechniqueRegistry::builtin();
+        let names: HashSet<_> = registry.entries().iter().map(TechniqueInfo::name).collect();
+        assert_eq!(names.len(), registry.entries().len());
+        assert_eq!(
+            registry.entries().len(),
+            all_techniques().len() + forcing_techniques().len()
+        );
+    }
+
+    #[test]
+    fn test_builtin_tiers_never_decrease() {
+        let registry = TechniqueRegistry::builtin();
+        for pair in registry.entries().windows(2) {
+            assert!(
+                pair[0].tier() <= pair[1].tier(),
+                "{} comes after {}",
+                pair[1].name(),
+                pair[0].name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_metadata() {
+        let registry = TechniqueRegistry::builtin();
+        assert_eq!(
+            registry.get("hidden singles").unwrap().tier(),
+            DifficultyTier::Easy
+        );
+        assert_eq!(
+            registry.get("nishio").unwrap().tier(),
+            DifficultyTier::Extreme
+        );
+        assert!(
+            registry
+                .get("unique rectangle type 1")
+                .unwrap()
+                .requires_unique_solution()
+        );
+        assert!(registry.get("unknown").is_none());
+    }
+
+    #[test]
+    fn test_select_keeps_registry_order() {
+        let registry = TechniqueRegistry::builtin();
+        let techniques = registry
+            .select(["xy-wing", "naked singles", "xy-wing"])
+            .unwrap();
+        assert_eq!(names(&techniques), ["naked singles", "xy-wing"]);
+
+        let err = registry.select(["naked singles", "guessing"]).unwrap_err();
+        assert_eq!(err.name(), "guessing");
+        assert_eq!(err.to_string(), "unknown technique 'guessing'");
+    }
+
+    #[test]
+    fn test_up_to_tier() {
+        let registry = TechniqueRegistry::builtin();
+        assert_eq!(
+            names(&registry.up_to_tier(DifficultyTier::Easy)),
+            names(&fundamental_techniques())
+        );
+        assert_eq!(
+            names(&registry.up_to_tier(DifficultyTier::Expert)),
+            names(&all_techniques())
+        );
+        assert_eq!(
+            registry.up_to_tier(DifficultyTier::Extreme).len(),
+            registry.entries().len()
+        );
+    }
+}
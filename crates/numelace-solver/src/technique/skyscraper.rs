@@ -0,0 +1,170 @@
+use numelace_core::{CandidateGrid, Digit, Position};
+
+use super::{
+    BoxedTechnique, basic_fish::Orientation, elimination::DigitElimination, links::conjugate_pairs,
+};
+use crate::{SolverError, Step, technique::Technique};
+
+/// A technique that finds two parallel conjugate pairs of a digit sharing one cross line.
+///
+/// A Skyscraper consists of two rows (or columns) in which a digit has exactly
+/// two candidates each. One end of each pair (the *base*) lies in the same column
+/// (or row), while the other ends (the *tops*) do not. At most one base can be
+/// true, so at least one top must be true. The digit is therefore removed from
+/// every cell that sees both tops.
+///
+/// Only the first skyscraper that yields eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{Skyscraper, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = Skyscraper::new();
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Skyscraper;
+
+impl Skyscraper {
+    /// Creates a new `Skyscraper` technique.
+    #[must_use]
+    pub const fn new() -> Self {
+        Skyscraper
+    }
+}
+
+impl Technique for Skyscraper {
+    fn name(&self) -> &'static str {
+        "skyscraper"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        for digit in Digit::ALL {
+            for orientation in [Orientation::Rows, Orientation::Columns] {
+                if let Some(elimination) = find_skyscraper(grid, digit, orientation) {
+                    return Ok(elimination.apply(self.name(), grid));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Finds a skyscraper for `digit` whose conjugate pairs lie in lines of the given
+/// orientation.
+fn find_skyscraper(
+    grid: &CandidateGrid,
+    digit: Digit,
+    orientation: Orientation,
+) -> Option<DigitElimination> {
+    let digit_positions = grid.digit_positions(digit);
+    let pairs: Vec<[Position; 2]> =
+        conjugate_pairs(digit_positions, (0..9).map(|line| orientation.base(line))).collect();
+
+    for (i, &[a1, a2]) in pairs.iter().enumerate() {
+        for &[b1, b2] in &pairs[i + 1..] {
+            for (base_a, top_a) in [(a1, a2), (a2, a1)] {
+                for (base_b, top_b) in [(b1, b2), (b2, b1)] {
+                    // Tops sharing a cross line would make this an X-Wing
+                    if orientation.cover_index(base_a) != orientation.cover_index(base_b)
+                        || orientation.cover_index(top_a) == orientation.cover_index(top_b)
+                    {
+                        continue;
+                    }
+                    let eliminations = top_a.house_peers() & top_b.house_peers() & digit_positions;
+                    if !eliminations.is_empty() {
+                        return Some(DigitElimination {
+                            digit,
+                            targets: eliminations,
+                            cells: [a1, a2, b1, b2].into_iter().collect(),
+                            houses: vec![
+                                orientation.base(orientation.base_index(a1)),
+                                orientation.base(orientation.base_index(b1)),
+                            ],
+                        });
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, Position};
+
+    use super::*;
+    use crate::testing::TechniqueTester;
+
+    /// Removes `digit` from every cell of row `y` except the given columns.
+    fn confine_row(grid: &mut CandidateGrid, digit: Digit, y: u8, cols: &[u8]) {
+        for x in 0..9 {
+            if !cols.contains(&x) {
+                grid.remove_candidate(Position::new(x, y), digit);
+            }
+        }
+    }
+
+    #[test]
+    fn test_skyscraper_in_rows() {
+        // D1: row 1 in columns 0 and 4, row 7 in columns 0 and 5
+        let mut grid = CandidateGrid::new();
+        confine_row(&mut grid, Digit::D1, 1, &[0, 4]);
+        confine_row(&mut grid, Digit::D1, 7, &[0, 5]);
+
+        // Tops (4, 1) and (5, 7) are both seen from column 5 in box 1 and column 4 in box 7
+        TechniqueTester::new(grid)
+            .apply_once(&Skyscraper::new())
+            .assert_removed_exact(Position::new(5, 0), [Digit::D1])
+            .assert_removed_exact(Position::new(5, 2), [Digit::D1])
+            .assert_removed_exact(Position::new(4, 6), [Digit::D1])
+            .assert_removed_exact(Position::new(4, 8), [Digit::D1])
+            .assert_no_change(Position::new(4, 1))
+            .assert_no_change(Position::new(5, 7))
+            .assert_no_change(Position::new(4, 4));
+    }
+
+    #[test]
+    fn test_skyscraper_in_columns() {
+        // D8: column 2 in rows 0 and 3, column 6 in rows 0 and 5
+        let mut grid = CandidateGrid::new();
+        for (x, rows) in [(2, [0, 3]), (6, [0, 5])] {
+            for y in 0..9 {
+                if !rows.contains(&y) {
+                    grid.remove_candidate(Position::new(x, y), Digit::D8);
+                }
+            }
+        }
+
+        // Tops (2, 3) and (6, 5) are both seen from row 3 in box 5 and row 5 in box 3
+        TechniqueTester::new(grid)
+            .apply_once(&Skyscraper::new())
+            .assert_removed_exact(Position::new(0, 5), [Digit::D8])
+            .assert_removed_exact(Position::new(8, 3), [Digit::D8])
+            .assert_no_change(Position::new(4, 4));
+    }
+
+    #[test]
+    fn test_no_change_for_x_wing() {
+        // Both ends aligned: this is an X-Wing, not a skyscraper
+        let mut grid = CandidateGrid::new();
+        confine_row(&mut grid, Digit::D1, 1, &[0, 4]);
+        confine_row(&mut grid, Digit::D1, 7, &[0, 4]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&Skyscraper::new())
+            .assert_no_change(Position::new(4, 0))
+            .assert_no_change(Position::new(0, 0));
+    }
+}
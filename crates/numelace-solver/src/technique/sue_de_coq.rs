@@ -0,0 +1,295 @@
+use numelace_core::{
+    CandidateGrid, Digit, DigitPositions, DigitSet, House, HouseMask, Position, containers::Array9,
+    index::CellIndexSemantics,
+};
+
+use super::{
+    BoxedTechnique,
+    combinations::{subsets, subsets_of_size},
+};
+use crate::{SolverError, Step, technique::Technique};
+
+/// A technique that splits the candidates of a box/line intersection between the line and the box.
+///
+/// Take 2-3 undecided cells `C` in the intersection of a box and a line, holding
+/// candidates `V` with at least two more digits than cells. Pair them with cells
+/// `A` in the rest of the line (candidates `VA`) and cells `B` in the rest of the
+/// box (candidates `VB`), where `VA` and `VB` share no digit. If the cells of
+/// `C`, `A` and `B` together hold exactly as many digits as there are cells, each
+/// digit is placed exactly once among them:
+///
+/// - Digits of `VA` are placed in `C` or `A`, so they are removed from the rest
+///   of the line.
+/// - Digits of `VB` are placed in `C` or `B`, so they are removed from the rest
+///   of the box.
+/// - Digits of `V` outside both `VA` and `VB` are placed in `C`, so they are
+///   removed from the rest of both the line and the box.
+///
+/// Only the first pattern that yields eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{SueDeCoq, Technique};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = SueDeCoq::new();
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SueDeCoq;
+
+impl SueDeCoq {
+    /// Creates a new `SueDeCoq` technique.
+    #[must_use]
+    pub const fn new() -> Self {
+        SueDeCoq
+    }
+}
+
+impl Technique for SueDeCoq {
+    fn name(&self) -> &'static str {
+        "sue de coq"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        let undecided = !grid.decided_cells();
+        for box_index in 0..9 {
+            let box_house = House::Box { index: box_index };
+            let origin = Position::box_origin(box_index);
+            let lines = (origin.y()..origin.y() + 3)
+                .map(|y| House::Row { y })
+                .chain((origin.x()..origin.x() + 3).map(|x| House::Column { x }));
+            for line in lines {
+                let intersection = line.positions() & box_house.positions() & undecided;
+                let line_groups = groups(grid, line, undecided & !box_house.positions());
+                let box_groups = groups(grid, box_house, undecided & !line.positions());
+                for size in 2..=3 {
+                    for cells in subsets_of_size(house_mask(line, intersection), size) {
+                        let cells = positions(line, cells);
+                        let digits = union(grid, cells);
+                        if digits.len() < size + 2 {
+                            continue;
+                        }
+                        let box_options: Vec<_> = options(&box_groups, size, digits).collect();
+                        for &(line_cells, line_digits) in options(&line_groups, size, digits) {
+                            for &&(box_cells, box_digits) in &box_options {
+                                let cell_count = size + line_cells.len() + box_cells.len();
+                                if !line_digits.is_disjoint(box_digits)
+                                    || (digits | line_digits | box_digits).len() != cell_count
+                                {
+                                    continue;
+                                }
+                                let pattern = Pattern {
+                                    cells,
+                                    digits,
+                                    line_cells: positions(line, line_cells),
+                                    line_digits,
+                                    box_cells: positions(box_house, box_cells),
+                                    box_digits,
+                                };
+                                let Some(eliminations) =
+                                    pattern.eliminations(grid, line, box_house)
+                                else {
+                                    continue;
+                                };
+                                let before = grid.clone();
+                                for (targets, digit) in eliminations {
+                                    grid.remove_candidate_with_mask(targets, digit);
+                                }
+                                let cells = pattern.cells | pattern.line_cells | pattern.box_cells;
+                                return Ok(Step::from_changes(self.name(), &before, grid).map(
+                                    |step| step.with_cells(cells).with_houses([line, box_house]),
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// The cells of a Sue de Coq candidate pattern, with the candidates they hold.
+struct Pattern {
+    /// Cells in the box/line intersection.
+    cells: DigitPositions,
+    digits: DigitSet,
+    /// Cells in the rest of the line.
+    line_cells: DigitPositions,
+    line_digits: DigitSet,
+    /// Cells in the rest of the box.
+    box_cells: DigitPositions,
+    box_digits: DigitSet,
+}
+
+impl Pattern {
+    /// Returns the eliminations of the pattern, or `None` if it yields nothing.
+    ///
+    /// The line and box digits must be disjoint, and the cells must hold exactly
+    /// as many digits as there are cells.
+    fn eliminations(
+        &self,
+        grid: &CandidateGrid,
+        line: House,
+        box_house: House,
+    ) -> Option<Vec<(DigitPositions, Digit)>> {
+        let all_digits = self.digits | self.line_digits | self.box_digits;
+        let line_targets = line.positions() & !self.cells & !self.line_cells;
+        let box_targets = box_house.positions() & !self.cells & !self.box_cells;
+        let mut eliminations = vec![];
+        for digit in all_digits {
+            let mut targets = DigitPositions::EMPTY;
+            if !self.box_digits.contains(digit) {
+                targets |= line_targets;
+            }
+            if !self.line_digits.contains(digit) {
+                targets |= box_targets;
+            }
+            targets &= grid.digit_positions(digit);
+            if !targets.is_empty() {
+                eliminations.push((targets, digit));
+            }
+        }
+        (!eliminations.is_empty()).then_some(eliminations)
+    }
+}
+
+/// Returns every non-empty group of the cells `positions` of `house`, with the
+/// candidates they hold.
+fn groups(
+    grid: &CandidateGrid,
+    house: House,
+    positions: DigitPositions,
+) -> Vec<(HouseMask, DigitSet)> {
+    let candidates =
+        Array9::<DigitSet, CellIndexSemantics>::from_fn(|i| grid.candidates_at(house.position(i)));
+    // Candidates of each group, built from the group without its first cell
+    let mut unions = [DigitSet::EMPTY; 1 << 9];
+    let mut groups = vec![];
+    for group in subsets(house_mask(house, positions)) {
+        let Some(first) = group.first() else {
+            continue;
+        };
+        let bits = usize::from(group.bits());
+        let digits = unions[bits & (bits - 1)] | candidates[first];
+        unions[bits] = digits;
+        groups.push((group, digits));
+    }
+    groups
+}
+
+/// Returns the groups that can extend `size` intersection cells holding `digits`.
+///
+/// A group must share a digit with the intersection without covering all of its
+/// digits, and its other digits must fit into its own cells. Together with the
+/// intersection, it must hold at least as many digits as cells, since the group
+/// on the other side adds at most as many digits as cells.
+fn options(
+    groups: &[(HouseMask, DigitSet)],
+    size: usize,
+    digits: DigitSet,
+) -> impl Iterator<Item = &(HouseMask, DigitSet)> {
+    groups.iter().filter(move |(group, candidates)| {
+        let extra = candidates.difference(digits).len();
+        !candidates.is_disjoint(digits)
+            && !digits.is_subset(*candidates)
+            && extra <= group.len()
+            && digits.len() + extra >= size + group.len()
+    })
+}
+
+/// Returns the cell indices of `positions` within `house`.
+fn house_mask(house: House, positions: DigitPositions) -> HouseMask {
+    positions
+        .iter()
+        .filter_map(|pos| house.cell_index(pos))
+        .collect()
+}
+
+/// Returns the positions of the cell indices `mask` within `house`.
+fn positions(house: House, mask: HouseMask) -> DigitPositions {
+    mask.iter().map(|i| house.position(i)).collect()
+}
+
+/// Returns the union of the candidates of `cells`.
+fn union(grid: &CandidateGrid, cells: DigitPositions) -> DigitSet {
+    cells
+        .iter()
+        .fold(DigitSet::EMPTY, |acc, pos| acc | grid.candidates_at(pos))
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, Position};
+
+    use super::*;
+    use crate::testing::{TechniqueTester, restrict};
+
+    #[test]
+    fn test_sue_de_coq() {
+        // C = (0, 0){1, 2, 3} + (1, 0){1, 2, 4}, A = (5, 0){1, 2}, B = (0, 2){3, 4}:
+        // four cells hold four digits
+        let mut grid = CandidateGrid::new();
+        restrict(
+            &mut grid,
+            Position::new(0, 0),
+            &[Digit::D1, Digit::D2, Digit::D3],
+        );
+        restrict(
+            &mut grid,
+            Position::new(1, 0),
+            &[Digit::D1, Digit::D2, Digit::D4],
+        );
+        restrict(&mut grid, Position::new(5, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(0, 2), &[Digit::D3, Digit::D4]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&SueDeCoq::new())
+            // D1 and D2 leave row 0, D3 and D4 leave box 0
+            .assert_removed_exact(Position::new(8, 0), [Digit::D1, Digit::D2])
+            .assert_removed_exact(Position::new(1, 1), [Digit::D3, Digit::D4])
+            .assert_removed_exact(
+                Position::new(2, 0),
+                [Digit::D1, Digit::D2, Digit::D3, Digit::D4],
+            )
+            .assert_no_change(Position::new(8, 1))
+            .assert_no_change(Position::new(0, 5));
+    }
+
+    #[test]
+    fn test_no_change_when_digits_overlap() {
+        // A and B share D1, so the digits cannot be split
+        let mut grid = CandidateGrid::new();
+        restrict(
+            &mut grid,
+            Position::new(0, 0),
+            &[Digit::D1, Digit::D2, Digit::D3],
+        );
+        restrict(
+            &mut grid,
+            Position::new(1, 0),
+            &[Digit::D1, Digit::D2, Digit::D4],
+        );
+        restrict(&mut grid, Position::new(5, 0), &[Digit::D1, Digit::D2]);
+        restrict(
+            &mut grid,
+            Position::new(0, 2),
+            &[Digit::D1, Digit::D3, Digit::D4],
+        );
+
+        TechniqueTester::new(grid)
+            .apply_once(&SueDeCoq::new())
+            .assert_no_change(Position::new(8, 0))
+            .assert_no_change(Position::new(1, 1));
+    }
+}
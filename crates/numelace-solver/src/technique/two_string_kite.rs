@@ -0,0 +1,153 @@
+use numelace_core::{CandidateGrid, Digit, House, Position};
+
+use super::{BoxedTechnique, elimination::DigitElimination, links::conjugate_pairs};
+use crate::{SolverError, Step, technique::Technique};
+
+/// A technique that finds a row and a column conjugate pair of a digit joined in a box.
+///
+/// A Two-String Kite consists of a row and a column in which a digit has exactly
+/// two candidates each. One end of the row pair and one end of the column pair
+/// lie in the same box (but are different cells), so at most one of them can be
+/// true. At least one of the other two ends must therefore be true, and the digit
+/// is removed from every cell that sees both of them.
+///
+/// Only the first kite that yields eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{Technique, TwoStringKite};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = TwoStringKite::new();
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TwoStringKite;
+
+impl TwoStringKite {
+    /// Creates a new `TwoStringKite` technique.
+    #[must_use]
+    pub const fn new() -> Self {
+        TwoStringKite
+    }
+}
+
+impl Technique for TwoStringKite {
+    fn name(&self) -> &'static str {
+        "2-string kite"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        for digit in Digit::ALL {
+            if let Some(elimination) = find_kite(grid, digit) {
+                return Ok(elimination.apply(self.name(), grid));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Finds a two-string kite for `digit`.
+fn find_kite(grid: &CandidateGrid, digit: Digit) -> Option<DigitElimination> {
+    let digit_positions = grid.digit_positions(digit);
+    let row_pairs: Vec<[Position; 2]> =
+        conjugate_pairs(digit_positions, (0..9).map(|y| House::Row { y })).collect();
+    let col_pairs: Vec<[Position; 2]> =
+        conjugate_pairs(digit_positions, (0..9).map(|x| House::Column { x })).collect();
+
+    for &[r1, r2] in &row_pairs {
+        for &[c1, c2] in &col_pairs {
+            for (row_inner, row_outer) in [(r1, r2), (r2, r1)] {
+                for (col_inner, col_outer) in [(c1, c2), (c2, c1)] {
+                    if row_inner.box_index() != col_inner.box_index()
+                        || row_inner == col_inner
+                        || row_outer == col_outer
+                    {
+                        continue;
+                    }
+                    let eliminations =
+                        row_outer.house_peers() & col_outer.house_peers() & digit_positions;
+                    if !eliminations.is_empty() {
+                        return Some(DigitElimination {
+                            digit,
+                            targets: eliminations,
+                            cells: [r1, r2, c1, c2].into_iter().collect(),
+                            houses: vec![
+                                House::Row { y: r1.y() },
+                                House::Column { x: c1.x() },
+                                House::Box {
+                                    index: row_inner.box_index(),
+                                },
+                            ],
+                        });
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, Position};
+
+    use super::*;
+    use crate::testing::TechniqueTester;
+
+    #[test]
+    fn test_two_string_kite() {
+        // D4: row 0 in columns 1 and 6, column 0 in rows 2 and 7; (1, 0) and (0, 2) share box 0
+        let mut grid = CandidateGrid::new();
+        for x in 0..9 {
+            if x != 1 && x != 6 {
+                grid.remove_candidate(Position::new(x, 0), Digit::D4);
+            }
+        }
+        for y in 1..9 {
+            if y != 2 && y != 7 {
+                grid.remove_candidate(Position::new(0, y), Digit::D4);
+            }
+        }
+
+        // (6, 7) sees both (6, 0) and (0, 7)
+        TechniqueTester::new(grid)
+            .apply_once(&TwoStringKite::new())
+            .assert_removed_exact(Position::new(6, 7), [Digit::D4])
+            .assert_no_change(Position::new(6, 0))
+            .assert_no_change(Position::new(0, 7))
+            .assert_no_change(Position::new(5, 7))
+            .assert_no_change(Position::new(6, 6));
+    }
+
+    #[test]
+    fn test_no_change_when_inner_ends_in_different_boxes() {
+        // D4: row 0 in columns 4 and 6, column 0 in rows 2 and 7
+        let mut grid = CandidateGrid::new();
+        for x in 0..9 {
+            if x != 4 && x != 6 {
+                grid.remove_candidate(Position::new(x, 0), Digit::D4);
+            }
+        }
+        for y in 1..9 {
+            if y != 2 && y != 7 {
+                grid.remove_candidate(Position::new(0, y), Digit::D4);
+            }
+        }
+
+        TechniqueTester::new(grid)
+            .apply_once(&TwoStringKite::new())
+            .assert_no_change(Position::new(6, 7))
+            .assert_no_change(Position::new(4, 7))
+            .assert_no_change(Position::new(4, 2));
+    }
+}
@@ -0,0 +1,429 @@
+use numelace_core::{CandidateGrid, Digit, DigitPositions, DigitSet, House, HouseMask, Position};
+
+use super::{BoxedTechnique, combinations::subsets_of_size};
+use crate::{SolverError, Step, technique::Technique};
+
+/// The variant of a [`UniqueRectangle`] pattern.
+///
+/// In all variants, four cells forming a rectangle across exactly two boxes share
+/// two candidates `{a, b}`. The *floor* cells contain only `{a, b}`, and the
+/// *roof* cells contain additional candidates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UniqueRectangleKind {
+    /// Three corners contain only `{a, b}`: `a` and `b` are removed from the fourth.
+    Type1,
+    /// Both roof cells contain exactly one extra candidate `c`: `c` is removed
+    /// from every cell that sees both roof cells.
+    Type2,
+    /// The roof cells' extra candidates form a naked subset with other cells in a
+    /// shared house: those digits are removed from the rest of the house.
+    Type3,
+    /// One of `a`/`b` is confined to the roof cells in a shared house: the other
+    /// is removed from both roof cells.
+    Type4,
+    /// A floor corner, plus conjugate pairs on `a` along the row and column of the
+    /// opposite corner: `b` is removed from the opposite corner.
+    Hidden,
+}
+
+/// A technique that avoids the deadly pattern of a rectangle with two interchangeable digits.
+///
+/// If four cells at the corners of a rectangle spanning exactly two boxes could
+/// all be resolved to only two digits `{a, b}`, the digits could be swapped to
+/// form a second solution. In a puzzle with a unique solution, this *deadly
+/// pattern* cannot occur, so at least one of the extra candidates must be true.
+/// See [`UniqueRectangleKind`] for the supported variants.
+///
+/// This technique is only valid for puzzles with a unique solution (see
+/// [`Technique::requires_unique_solution`]).
+///
+/// Only the first rectangle that yields eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{Technique, UniqueRectangle, UniqueRectangleKind};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = UniqueRectangle::new(UniqueRectangleKind::Type1);
+/// assert_eq!(technique.name(), "unique rectangle type 1");
+/// assert!(technique.requires_unique_solution());
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct UniqueRectangle {
+    kind: UniqueRectangleKind,
+}
+
+impl UniqueRectangle {
+    /// Creates a new `UniqueRectangle` technique for the given variant.
+    #[must_use]
+    pub const fn new(kind: UniqueRectangleKind) -> Self {
+        Self { kind }
+    }
+
+    /// Returns the variant of the pattern this technique finds.
+    #[must_use]
+    pub const fn kind(&self) -> UniqueRectangleKind {
+        self.kind
+    }
+}
+
+impl Technique for UniqueRectangle {
+    fn name(&self) -> &'static str {
+        match self.kind {
+            UniqueRectangleKind::Type1 => "unique rectangle type 1",
+            UniqueRectangleKind::Type2 => "unique rectangle type 2",
+            UniqueRectangleKind::Type3 => "unique rectangle type 3",
+            UniqueRectangleKind::Type4 => "unique rectangle type 4",
+            UniqueRectangleKind::Hidden => "hidden unique rectangle",
+        }
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn requires_unique_solution(&self) -> bool {
+        true
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        for rectangle in rectangles(grid) {
+            let eliminations = match self.kind {
+                UniqueRectangleKind::Type1 => rectangle.find_type1(grid),
+                UniqueRectangleKind::Type2 => rectangle.find_type2(grid),
+                UniqueRectangleKind::Type3 => rectangle.find_type3(grid),
+                UniqueRectangleKind::Type4 => rectangle.find_type4(grid),
+                UniqueRectangleKind::Hidden => rectangle.find_hidden(grid),
+            };
+            if eliminations.is_empty() {
+                continue;
+            }
+            let before = grid.clone();
+            for (positions, digit) in eliminations {
+                grid.remove_candidate_with_mask(positions, digit);
+            }
+            let cells = DigitPositions::from_iter(rectangle.corners);
+            return Ok(
+                Step::from_changes(self.name(), &before, grid).map(|step| step.with_cells(cells))
+            );
+        }
+        Ok(None)
+    }
+}
+
+/// Four cells at the corners of a rectangle spanning two boxes, sharing two candidates.
+#[derive(Debug, Clone, Copy)]
+struct Rectangle {
+    /// Corners in the order `(x1, y1)`, `(x2, y1)`, `(x1, y2)`, `(x2, y2)`, so
+    /// that corner `i` and corner `3 - i` are diagonally opposite.
+    corners: [Position; 4],
+    digits: DigitSet,
+}
+
+/// Candidate eliminations, grouped by digit.
+type Eliminations = Vec<(DigitPositions, Digit)>;
+
+/// Returns every rectangle spanning exactly two boxes whose corners share two candidates.
+fn rectangles(grid: &CandidateGrid) -> Vec<Rectangle> {
+    let mut rectangles = vec![];
+    for y1 in 0..9 {
+        for y2 in y1 + 1..9 {
+            for x1 in 0..9 {
+                for x2 in x1 + 1..9 {
+                    // Exactly one of the two lines pairs must stay within a band
+                    if (y1 / 3 == y2 / 3) == (x1 / 3 == x2 / 3) {
+                        continue;
+                    }
+                    let corners = [
+                        Position::new(x1, y1),
+                        Position::new(x2, y1),
+                        Position::new(x1, y2),
+                        Position::new(x2, y2),
+                    ];
+                    let common = corners
+                        .iter()
+                        .fold(DigitSet::FULL, |acc, &pos| acc & grid.candidates_at(pos));
+                    for digits in subsets_of_size(common, 2) {
+                        rectangles.push(Rectangle { corners, digits });
+                    }
+                }
+            }
+        }
+    }
+    rectangles
+}
+
+impl Rectangle {
+    /// Returns the corners whose candidates are exactly the rectangle's digits.
+    fn floor(&self, grid: &CandidateGrid) -> Vec<usize> {
+        (0..4)
+            .filter(|&i| grid.candidates_at(self.corners[i]) == self.digits)
+            .collect()
+    }
+
+    /// Returns the two non-floor corners, if exactly two corners are floor cells.
+    fn roof(&self, grid: &CandidateGrid) -> Option<[Position; 2]> {
+        let floor = self.floor(grid);
+        if floor.len() != 2 {
+            return None;
+        }
+        let mut roof = (0..4)
+            .filter(|i| !floor.contains(i))
+            .map(|i| self.corners[i]);
+        Some([roof.next()?, roof.next()?])
+    }
+
+    fn find_type1(&self, grid: &CandidateGrid) -> Eliminations {
+        let floor = self.floor(grid);
+        if floor.len() != 3 {
+            return vec![];
+        }
+        let Some(target) = (0..4).find(|i| !floor.contains(i)) else {
+            return vec![];
+        };
+        let target = DigitPositions::from_iter([self.corners[target]]);
+        self.digits.iter().map(|digit| (target, digit)).collect()
+    }
+
+    fn find_type2(&self, grid: &CandidateGrid) -> Eliminations {
+        let Some([roof1, roof2]) = self.roof(grid) else {
+            return vec![];
+        };
+        let candidates = grid.candidates_at(roof1);
+        let extra = candidates & !self.digits;
+        if candidates != grid.candidates_at(roof2) || extra.len() != 1 {
+            return vec![];
+        }
+        let Some(digit) = extra.first() else {
+            return vec![];
+        };
+        let positions = roof1.house_peers() & roof2.house_peers() & grid.digit_positions(digit);
+        if positions.is_empty() {
+            return vec![];
+        }
+        vec![(positions, digit)]
+    }
+
+    fn find_type3(&self, grid: &CandidateGrid) -> Eliminations {
+        let Some(roof) = self.roof(grid) else {
+            return vec![];
+        };
+        let extra = (grid.candidates_at(roof[0]) | grid.candidates_at(roof[1])) & !self.digits;
+        for house in shared_houses(roof) {
+            // The roof acts as a single virtual cell holding the extra candidates
+            let mut others = HouseMask::new();
+            for i in 0..9 {
+                let pos = house.position(i);
+                if !roof.contains(&pos) && grid.candidates_at(pos).len() >= 2 {
+                    others.insert(i);
+                }
+            }
+            for size in 1..=3 {
+                for subset in subsets_of_size(others, size) {
+                    let digits = subset
+                        .iter()
+                        .fold(extra, |acc, i| acc | grid.candidates_at(house.position(i)));
+                    if digits.len() != size + 1 {
+                        continue;
+                    }
+                    let subset_positions: DigitPositions =
+                        subset.iter().map(|i| house.position(i)).collect();
+                    let rest =
+                        house.positions() & !subset_positions & !DigitPositions::from_iter(roof);
+                    let eliminations: Eliminations = digits
+                        .iter()
+                        .map(|digit| (rest & grid.digit_positions(digit), digit))
+                        .filter(|(positions, _)| !positions.is_empty())
+                        .collect();
+                    if !eliminations.is_empty() {
+                        return eliminations;
+                    }
+                }
+            }
+        }
+        vec![]
+    }
+
+    fn find_type4(&self, grid: &CandidateGrid) -> Eliminations {
+        let Some(roof) = self.roof(grid) else {
+            return vec![];
+        };
+        let roof_positions = DigitPositions::from_iter(roof);
+        for house in shared_houses(roof) {
+            for locked in self.digits {
+                if grid.digit_positions(locked) & house.positions() != roof_positions {
+                    continue;
+                }
+                let Some(other) = self.digits.iter().find(|&d| d != locked) else {
+                    continue;
+                };
+                return vec![(roof_positions, other)];
+            }
+        }
+        vec![]
+    }
+
+    fn find_hidden(&self, grid: &CandidateGrid) -> Eliminations {
+        for corner in self.floor(grid) {
+            let target = self.corners[3 - corner];
+            // The other two corners share a row or column with the target
+            let row = DigitPositions::ROW_POSITIONS[target.y()];
+            let col = DigitPositions::COLUMN_POSITIONS[target.x()];
+            let rectangle = DigitPositions::from_iter(self.corners);
+            for strong in self.digits {
+                let positions = grid.digit_positions(strong);
+                if positions & row != rectangle & row || positions & col != rectangle & col {
+                    continue;
+                }
+                let Some(other) = self.digits.iter().find(|&d| d != strong) else {
+                    continue;
+                };
+                return vec![(DigitPositions::from_iter([target]), other)];
+            }
+        }
+        vec![]
+    }
+}
+
+/// Returns the houses containing both cells.
+fn shared_houses([a, b]: [Position; 2]) -> Vec<House> {
+    let mut houses = vec![];
+    if a.y() == b.y() {
+        houses.push(House::Row { y: a.y() });
+    }
+    if a.x() == b.x() {
+        houses.push(House::Column { x: a.x() });
+    }
+    if a.box_index() == b.box_index() {
+        houses.push(House::Box {
+            index: a.box_index(),
+        });
+    }
+    houses
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, Position};
+
+    use super::*;
+    use crate::testing::{TechniqueTester, restrict};
+
+    // Rectangle used by the tests: (0, 0), (4, 0), (0, 1), (4, 1) in boxes 0 and 1
+    const A: Position = Position::new(0, 0);
+    const B: Position = Position::new(4, 0);
+    const C: Position = Position::new(0, 1);
+    const D: Position = Position::new(4, 1);
+
+    #[test]
+    fn test_type1() {
+        let mut grid = CandidateGrid::new();
+        for pos in [A, B, C] {
+            restrict(&mut grid, pos, &[Digit::D1, Digit::D2]);
+        }
+        restrict(&mut grid, D, &[Digit::D1, Digit::D2, Digit::D5, Digit::D6]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&UniqueRectangle::new(UniqueRectangleKind::Type1))
+            .assert_removed_exact(D, [Digit::D1, Digit::D2])
+            .assert_no_change(A)
+            .assert_no_change(B);
+    }
+
+    #[test]
+    fn test_type2() {
+        // Roof (4, 0) and (4, 1) are both {1, 2, 7}
+        let mut grid = CandidateGrid::new();
+        for pos in [A, C] {
+            restrict(&mut grid, pos, &[Digit::D1, Digit::D2]);
+        }
+        for pos in [B, D] {
+            restrict(&mut grid, pos, &[Digit::D1, Digit::D2, Digit::D7]);
+        }
+
+        TechniqueTester::new(grid)
+            .apply_once(&UniqueRectangle::new(UniqueRectangleKind::Type2))
+            .assert_removed_exact(Position::new(4, 5), [Digit::D7])
+            .assert_removed_exact(Position::new(3, 2), [Digit::D7])
+            .assert_no_change(Position::new(5, 5))
+            .assert_no_change(B);
+    }
+
+    #[test]
+    fn test_type3() {
+        // Roof extras {7, 8} form a naked pair with (4, 6) {7, 8} in column 4
+        let mut grid = CandidateGrid::new();
+        for pos in [A, C] {
+            restrict(&mut grid, pos, &[Digit::D1, Digit::D2]);
+        }
+        restrict(&mut grid, B, &[Digit::D1, Digit::D2, Digit::D7]);
+        restrict(&mut grid, D, &[Digit::D1, Digit::D2, Digit::D8]);
+        restrict(&mut grid, Position::new(4, 6), &[Digit::D7, Digit::D8]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&UniqueRectangle::new(UniqueRectangleKind::Type3))
+            .assert_removed_exact(Position::new(4, 3), [Digit::D7, Digit::D8])
+            .assert_removed_exact(Position::new(4, 8), [Digit::D7, Digit::D8])
+            .assert_no_change(Position::new(4, 6))
+            .assert_no_change(Position::new(3, 3));
+    }
+
+    #[test]
+    fn test_type4() {
+        // D1 in column 4 appears only in the roof cells
+        let mut grid = CandidateGrid::new();
+        for pos in [A, C] {
+            restrict(&mut grid, pos, &[Digit::D1, Digit::D2]);
+        }
+        for y in 2..9 {
+            grid.remove_candidate(Position::new(4, y), Digit::D1);
+        }
+
+        TechniqueTester::new(grid)
+            .apply_once(&UniqueRectangle::new(UniqueRectangleKind::Type4))
+            .assert_removed_exact(B, [Digit::D2])
+            .assert_removed_exact(D, [Digit::D2])
+            .assert_no_change(A);
+    }
+
+    #[test]
+    fn test_hidden() {
+        // A is the floor; D1 in row 1 and column 4 lies only on the rectangle
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, A, &[Digit::D1, Digit::D2]);
+        for x in [1, 2, 3, 5, 6, 7, 8] {
+            grid.remove_candidate(Position::new(x, 1), Digit::D1);
+        }
+        for y in 2..9 {
+            grid.remove_candidate(Position::new(4, y), Digit::D1);
+        }
+
+        TechniqueTester::new(grid)
+            .apply_once(&UniqueRectangle::new(UniqueRectangleKind::Hidden))
+            .assert_removed_exact(D, [Digit::D2])
+            .assert_no_change(B)
+            .assert_no_change(C);
+    }
+
+    #[test]
+    fn test_no_change_when_rectangle_spans_four_boxes() {
+        let mut grid = CandidateGrid::new();
+        for pos in [
+            Position::new(0, 0),
+            Position::new(4, 0),
+            Position::new(0, 4),
+        ] {
+            restrict(&mut grid, pos, &[Digit::D1, Digit::D2]);
+        }
+
+        TechniqueTester::new(grid)
+            .apply_once(&UniqueRectangle::new(UniqueRectangleKind::Type1))
+            .assert_no_change(Position::new(4, 4));
+    }
+}
@@ -0,0 +1,155 @@
+use numelace_core::{CandidateGrid, Digit, DigitPositions, House, Position};
+
+use super::{BoxedTechnique, elimination::DigitElimination, links::conjugate_pairs};
+use crate::{SolverError, Step, technique::Technique};
+
+/// A technique that finds two identical bivalue cells connected by a strong link.
+///
+/// A W-Wing consists of two cells with the same candidates `{x, y}` that do not
+/// see each other, plus a house in which `x` has exactly two candidates, one
+/// seeing each bivalue cell. If neither bivalue cell were `y`, both would be `x`,
+/// leaving no place for `x` in the linking house. So one of them must be `y`, and
+/// `y` is removed from every cell that sees both bivalue cells.
+///
+/// Only the first wing that yields eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{Technique, WWing};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = WWing::new();
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WWing;
+
+impl WWing {
+    /// Creates a new `WWing` technique.
+    #[must_use]
+    pub const fn new() -> Self {
+        WWing
+    }
+}
+
+impl Technique for WWing {
+    fn name(&self) -> &'static str {
+        "w-wing"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        Ok(find_w_wing(grid).and_then(|elimination| elimination.apply(self.name(), grid)))
+    }
+}
+
+/// Finds a W-Wing, returning the eliminations of `y`.
+fn find_w_wing(grid: &CandidateGrid) -> Option<DigitElimination> {
+    let [.., bivalue] = grid.classify_cells::<3>();
+    let cells: Vec<Position> = bivalue.into_iter().collect();
+    for (i, &cell1) in cells.iter().enumerate() {
+        let candidates = grid.candidates_at(cell1);
+        let peers1 = cell1.house_peers();
+        for &cell2 in &cells[i + 1..] {
+            if grid.candidates_at(cell2) != candidates || peers1.contains(cell2) {
+                continue;
+            }
+            let peers2 = cell2.house_peers();
+            for x in candidates {
+                let Some(y) = candidates.iter().find(|&d| d != x) else {
+                    continue;
+                };
+                let eliminations = peers1 & peers2 & grid.digit_positions(y);
+                if eliminations.is_empty() {
+                    continue;
+                }
+                let Some([a, b]) = strong_link(grid, x, peers1, peers2) else {
+                    continue;
+                };
+                return Some(DigitElimination {
+                    digit: y,
+                    targets: eliminations,
+                    cells: [cell1, cell2, a, b].into_iter().collect(),
+                    houses: vec![],
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Returns a conjugate pair of `digit` with one end in `peers1` and the other in
+/// `peers2`.
+fn strong_link(
+    grid: &CandidateGrid,
+    digit: Digit,
+    peers1: DigitPositions,
+    peers2: DigitPositions,
+) -> Option<[Position; 2]> {
+    conjugate_pairs(grid.digit_positions(digit), House::ALL).find(|&[a, b]| {
+        (peers1.contains(a) && peers2.contains(b)) || (peers1.contains(b) && peers2.contains(a))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, Position};
+
+    use super::*;
+    use crate::testing::{TechniqueTester, restrict};
+
+    #[test]
+    fn test_w_wing() {
+        // (0, 0) and (8, 4) are both {1, 2}; D1 in row 8 lies only in (0, 8) and (8, 8)
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(8, 4), &[Digit::D1, Digit::D2]);
+        for x in 1..8 {
+            grid.remove_candidate(Position::new(x, 8), Digit::D1);
+        }
+
+        // (8, 0) and (0, 4) see both bivalue cells
+        TechniqueTester::new(grid)
+            .apply_once(&WWing::new())
+            .assert_removed_exact(Position::new(8, 0), [Digit::D2])
+            .assert_removed_exact(Position::new(0, 4), [Digit::D2])
+            .assert_no_change(Position::new(4, 0))
+            .assert_no_change(Position::new(0, 8));
+    }
+
+    #[test]
+    fn test_no_change_without_strong_link() {
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(8, 4), &[Digit::D1, Digit::D2]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&WWing::new())
+            .assert_no_change(Position::new(8, 0))
+            .assert_no_change(Position::new(0, 4));
+    }
+
+    #[test]
+    fn test_no_change_when_cells_see_each_other() {
+        // Identical bivalue cells in the same row form a naked pair, not a W-Wing
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(8, 0), &[Digit::D1, Digit::D2]);
+        for x in 1..8 {
+            grid.remove_candidate(Position::new(x, 8), Digit::D1);
+        }
+
+        TechniqueTester::new(grid)
+            .apply_once(&WWing::new())
+            .assert_no_change(Position::new(4, 0))
+            .assert_no_change(Position::new(0, 4));
+    }
+}
@@ -0,0 +1,146 @@
+use numelace_core::{CandidateGrid, Position};
+
+use super::{BoxedTechnique, elimination::DigitElimination};
+use crate::{SolverError, Step, technique::Technique};
+
+/// A technique that finds a bivalue pivot cell with two bivalue pincers.
+///
+/// An XY-Wing consists of a pivot cell with candidates `{x, y}` and two pincer
+/// cells that see the pivot, with candidates `{x, z}` and `{y, z}`. Whichever
+/// digit the pivot takes, one of the pincers must be `z`. The digit `z` is
+/// therefore removed from every cell that sees both pincers.
+///
+/// Only the first wing that yields eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{Technique, XyWing};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = XyWing::new();
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct XyWing;
+
+impl XyWing {
+    /// Creates a new `XyWing` technique.
+    #[must_use]
+    pub const fn new() -> Self {
+        XyWing
+    }
+}
+
+impl Technique for XyWing {
+    fn name(&self) -> &'static str {
+        "xy-wing"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        Ok(find_xy_wing(grid).and_then(|elimination| elimination.apply(self.name(), grid)))
+    }
+}
+
+/// Finds an XY-Wing, returning the eliminations of `z`.
+fn find_xy_wing(grid: &CandidateGrid) -> Option<DigitElimination> {
+    let [.., bivalue] = grid.classify_cells::<3>();
+    for pivot in bivalue {
+        let pivot_candidates = grid.candidates_at(pivot);
+        let pincers: Vec<Position> = (bivalue & pivot.house_peers()).into_iter().collect();
+        for (i, &pincer1) in pincers.iter().enumerate() {
+            let candidates1 = grid.candidates_at(pincer1);
+            for &pincer2 in &pincers[i + 1..] {
+                let candidates2 = grid.candidates_at(pincer2);
+                // {x, y}, {x, z}, {y, z}: three distinct pairs drawn from three digits
+                if candidates1 == pivot_candidates
+                    || candidates2 == pivot_candidates
+                    || candidates1 == candidates2
+                    || (pivot_candidates | candidates1 | candidates2).len() != 3
+                {
+                    continue;
+                }
+                let Some(z) = (candidates1 & candidates2).first() else {
+                    continue;
+                };
+                let eliminations =
+                    pincer1.house_peers() & pincer2.house_peers() & grid.digit_positions(z);
+                if !eliminations.is_empty() {
+                    return Some(DigitElimination {
+                        digit: z,
+                        targets: eliminations,
+                        cells: [pivot, pincer1, pincer2].into_iter().collect(),
+                        houses: vec![],
+                    });
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, Position};
+
+    use super::*;
+    use crate::testing::{TechniqueTester, restrict};
+
+    #[test]
+    fn test_xy_wing() {
+        // Pivot (0, 0) {1, 2}, pincers (5, 0) {1, 3} in the row and (1, 2) {2, 3} in the box
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(5, 0), &[Digit::D1, Digit::D3]);
+        restrict(&mut grid, Position::new(1, 2), &[Digit::D2, Digit::D3]);
+
+        // Cells seeing both pincers: (1, 0), (2, 0) and (3, 2), (4, 2), (5, 2)
+        TechniqueTester::new(grid)
+            .apply_once(&XyWing::new())
+            .assert_removed_exact(Position::new(1, 0), [Digit::D3])
+            .assert_removed_exact(Position::new(2, 0), [Digit::D3])
+            .assert_removed_exact(Position::new(4, 2), [Digit::D3])
+            .assert_no_change(Position::new(0, 0))
+            .assert_no_change(Position::new(6, 0))
+            .assert_no_change(Position::new(1, 5));
+    }
+
+    #[test]
+    fn test_xy_wing_with_pincer_in_pivot_box() {
+        // Pivot (4, 4) {5, 6}, pincers (3, 3) {5, 9} and (4, 8) {6, 9}
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(4, 4), &[Digit::D5, Digit::D6]);
+        restrict(&mut grid, Position::new(3, 3), &[Digit::D5, Digit::D9]);
+        restrict(&mut grid, Position::new(4, 8), &[Digit::D6, Digit::D9]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&XyWing::new())
+            .assert_removed_exact(Position::new(4, 3), [Digit::D9])
+            .assert_removed_exact(Position::new(4, 5), [Digit::D9])
+            .assert_removed_exact(Position::new(3, 8), [Digit::D9])
+            .assert_no_change(Position::new(5, 3))
+            .assert_no_change(Position::new(4, 0));
+    }
+
+    #[test]
+    fn test_no_change_when_pincers_share_pivot_digit() {
+        // Pincers {1, 3} and {1, 3} do not form a wing
+        let mut grid = CandidateGrid::new();
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D2]);
+        restrict(&mut grid, Position::new(5, 0), &[Digit::D1, Digit::D3]);
+        restrict(&mut grid, Position::new(1, 2), &[Digit::D1, Digit::D3]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&XyWing::new())
+            .assert_no_change(Position::new(1, 0))
+            .assert_no_change(Position::new(4, 2));
+    }
+}
@@ -0,0 +1,138 @@
+use numelace_core::{CandidateGrid, Position};
+
+use super::{BoxedTechnique, elimination::DigitElimination};
+use crate::{SolverError, Step, technique::Technique};
+
+/// A technique that finds a trivalue pivot cell with two bivalue pincers.
+///
+/// An XYZ-Wing consists of a pivot cell with candidates `{x, y, z}` and two
+/// pincer cells that see the pivot, with candidates `{x, z}` and `{y, z}`.
+/// Whichever digit the pivot takes, one of the three cells must be `z`. The
+/// digit `z` is therefore removed from every cell that sees the pivot and both
+/// pincers.
+///
+/// Only the first wing that yields eliminations is applied.
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::CandidateGrid;
+/// use numelace_solver::technique::{Technique, XyzWing};
+///
+/// let mut grid = CandidateGrid::new();
+/// let technique = XyzWing::new();
+///
+/// // Apply the technique
+/// let step = technique.apply(&mut grid)?;
+/// # Ok::<(), numelace_solver::SolverError>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct XyzWing;
+
+impl XyzWing {
+    /// Creates a new `XyzWing` technique.
+    #[must_use]
+    pub const fn new() -> Self {
+        XyzWing
+    }
+}
+
+impl Technique for XyzWing {
+    fn name(&self) -> &'static str {
+        "xyz-wing"
+    }
+
+    fn clone_box(&self) -> BoxedTechnique {
+        Box::new(*self)
+    }
+
+    fn apply(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
+        Ok(find_xyz_wing(grid).and_then(|elimination| elimination.apply(self.name(), grid)))
+    }
+}
+
+/// Finds an XYZ-Wing, returning the eliminations of `z`.
+fn find_xyz_wing(grid: &CandidateGrid) -> Option<DigitElimination> {
+    let [.., bivalue, trivalue] = grid.classify_cells::<4>();
+    for pivot in trivalue {
+        let pivot_candidates = grid.candidates_at(pivot);
+        let pincers: Vec<Position> = (bivalue & pivot.house_peers())
+            .into_iter()
+            .filter(|&pincer| grid.candidates_at(pincer).is_subset(pivot_candidates))
+            .collect();
+        for (i, &pincer1) in pincers.iter().enumerate() {
+            let candidates1 = grid.candidates_at(pincer1);
+            for &pincer2 in &pincers[i + 1..] {
+                let candidates2 = grid.candidates_at(pincer2);
+                if candidates1 == candidates2 {
+                    continue;
+                }
+                // Two distinct pairs within {x, y, z} always share exactly one digit
+                let Some(z) = (candidates1 & candidates2).first() else {
+                    continue;
+                };
+                let eliminations = pivot.house_peers()
+                    & pincer1.house_peers()
+                    & pincer2.house_peers()
+                    & grid.digit_positions(z);
+                if !eliminations.is_empty() {
+                    return Some(DigitElimination {
+                        digit: z,
+                        targets: eliminations,
+                        cells: [pivot, pincer1, pincer2].into_iter().collect(),
+                        houses: vec![],
+                    });
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use numelace_core::{CandidateGrid, Digit, Position};
+
+    use super::*;
+    use crate::testing::{TechniqueTester, restrict};
+
+    #[test]
+    fn test_xyz_wing() {
+        // Pivot (1, 1) {1, 2, 3}, pincers (0, 0) {1, 3} in the box and (7, 1) {2, 3} in the row
+        let mut grid = CandidateGrid::new();
+        restrict(
+            &mut grid,
+            Position::new(1, 1),
+            &[Digit::D1, Digit::D2, Digit::D3],
+        );
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D3]);
+        restrict(&mut grid, Position::new(7, 1), &[Digit::D2, Digit::D3]);
+
+        // Only the other box cells in row 1 see all three
+        TechniqueTester::new(grid)
+            .apply_once(&XyzWing::new())
+            .assert_removed_exact(Position::new(0, 1), [Digit::D3])
+            .assert_removed_exact(Position::new(2, 1), [Digit::D3])
+            .assert_no_change(Position::new(1, 1))
+            .assert_no_change(Position::new(1, 0))
+            .assert_no_change(Position::new(4, 1));
+    }
+
+    #[test]
+    fn test_no_change_when_pincer_not_subset_of_pivot() {
+        // Pincer (7, 1) {2, 4} is not a subset of the pivot's candidates
+        let mut grid = CandidateGrid::new();
+        restrict(
+            &mut grid,
+            Position::new(1, 1),
+            &[Digit::D1, Digit::D2, Digit::D3],
+        );
+        restrict(&mut grid, Position::new(0, 0), &[Digit::D1, Digit::D3]);
+        restrict(&mut grid, Position::new(7, 1), &[Digit::D2, Digit::D4]);
+
+        TechniqueTester::new(grid)
+            .apply_once(&XyzWing::new())
+            .assert_no_change(Position::new(0, 1))
+            .assert_no_change(Position::new(2, 1));
+    }
+}
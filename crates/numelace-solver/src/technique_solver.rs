@@ -3,8 +3,8 @@ use std::collections::HashMap;
 use numelace_core::CandidateGrid;
 
 use crate::{
-    SolverError,
-    technique::{self, BoxedTechnique},
+    DifficultyTier, SolveControl, SolveTrace, SolverError, Step, TraceStep,
+    technique::{self, BoxedTechnique, TechniqueRegistry, UnknownTechniqueError},
 };
 
 /// Statistics collected during technique-based solving.
@@ -123,7 +123,7 @@ impl TechniqueSolverStats {
 /// let mut grid = CandidateGrid::new();
 /// let mut stats = TechniqueSolverStats::new();
 ///
-/// while solver.step(&mut grid, &mut stats)? {
+/// while solver.step(&mut grid, &mut stats)?.is_some() {
 ///     println!("Progress made! Step {}", stats.total_steps());
 ///     if grid.is_solved()? {
 ///         break;
@@ -131,9 +131,25 @@ impl TechniqueSolverStats {
 /// }
 /// # Ok::<(), numelace_solver::SolverError>(())
 /// ```
+///
+/// # Uniqueness techniques
+///
+/// Techniques that assume the puzzle has a unique solution (see
+/// [`Technique::requires_unique_solution`](crate::technique::Technique::requires_unique_solution))
+/// are skipped by default, even when they are part of the technique list. Enable
+/// them with [`assume_unique_solution`](Self::assume_unique_solution) only for
+/// puzzles known to be unique, such as those from `PuzzleGenerator`.
+///
+/// ```
+/// use numelace_solver::TechniqueSolver;
+///
+/// let solver = TechniqueSolver::with_all_techniques().assume_unique_solution(true);
+/// assert!(solver.assumes_unique_solution());
+/// ```
 #[derive(Debug, Clone)]
 pub struct TechniqueSolver {
     techniques: Vec<BoxedTechnique>,
+    assume_unique_solution: bool,
 }
 
 impl TechniqueSolver {
@@ -156,7 +172,10 @@ impl TechniqueSolver {
     /// ```
     #[must_use]
     pub fn new(techniques: Vec<BoxedTechnique>) -> Self {
-        Self { techniques }
+        Self {
+            techniques,
+            assume_unique_solution: false,
+        }
     }
 
     /// Creates a new solver with all available techniques.
@@ -173,9 +192,7 @@ impl TechniqueSolver {
     /// ```
     #[must_use]
     pub fn with_all_techniques() -> Self {
-        Self {
-            techniques: technique::all_techniques(),
-        }
+        Self::new(technique::all_techniques())
     }
 
     /// Creates a new solver with fundamental techniques.
@@ -203,14 +220,97 @@ impl TechniqueSolver {
     #[must_use]
     pub fn with_fundamental_techniques() -> Self {
         let techniques = technique::fundamental_techniques();
-        Self { techniques }
+        Self::new(techniques)
+    }
+
+    /// Creates a new solver with the built-in techniques named in `names`.
+    ///
+    /// Techniques are applied in the order of [`TechniqueRegistry::builtin`],
+    /// not in the order of `names`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnknownTechniqueError`] if a name is not a built-in technique.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_solver::TechniqueSolver;
+    ///
+    /// let solver = TechniqueSolver::from_technique_names(["naked singles", "x-wing"])?;
+    /// assert!(TechniqueSolver::from_technique_names(["guessing"]).is_err());
+    /// # Ok::<(), numelace_solver::technique::UnknownTechniqueError>(())
+    /// ```
+    pub fn from_technique_names<I, S>(names: I) -> Result<Self, UnknownTechniqueError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Ok(Self::new(TechniqueRegistry::builtin().select(names)?))
+    }
+
+    /// Creates a new solver with the built-in techniques of tier `max_tier` or
+    /// easier.
+    ///
+    /// Uniqueness techniques are included, but still only applied when
+    /// [`assume_unique_solution`](Self::assume_unique_solution) is enabled. The
+    /// forcing techniques are only included for
+    /// [`DifficultyTier::Extreme`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_solver::{DifficultyTier, TechniqueSolver};
+    ///
+    /// // Only singles, locked candidates and subsets
+    /// let solver = TechniqueSolver::with_max_tier(DifficultyTier::Medium);
+    /// ```
+    #[must_use]
+    pub fn with_max_tier(max_tier: DifficultyTier) -> Self {
+        Self::new(TechniqueRegistry::builtin().up_to_tier(max_tier))
+    }
+
+    /// Sets whether the puzzle may be assumed to have a unique solution.
+    ///
+    /// When `true`, techniques that rely on uniqueness (such as Unique Rectangles)
+    /// are applied along with the others. When `false` (the default), they are
+    /// skipped.
+    ///
+    /// Only enable this for puzzles whose uniqueness is guaranteed. On a puzzle
+    /// with multiple solutions, uniqueness techniques may remove candidates that
+    /// belong to a valid solution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_solver::TechniqueSolver;
+    ///
+    /// let solver = TechniqueSolver::with_all_techniques();
+    /// assert!(!solver.assumes_unique_solution());
+    ///
+    /// let solver = solver.assume_unique_solution(true);
+    /// assert!(solver.assumes_unique_solution());
+    /// ```
+    #[must_use]
+    pub fn assume_unique_solution(mut self, assume: bool) -> Self {
+        self.assume_unique_solution = assume;
+        self
+    }
+
+    /// Returns `true` if uniqueness techniques are enabled.
+    ///
+    /// See [`assume_unique_solution`](Self::assume_unique_solution).
+    #[must_use]
+    pub fn assumes_unique_solution(&self) -> bool {
+        self.assume_unique_solution
     }
 
     /// Applies one step of solving by trying each technique in order.
     ///
     /// Iterates through the list of techniques, applying the first one that
     /// makes progress. When a technique succeeds, the statistics are updated
-    /// and the method returns immediately.
+    /// and the method returns immediately. Uniqueness techniques are skipped
+    /// unless [`assume_unique_solution`](Self::assume_unique_solution) is enabled.
     ///
     /// # Arguments
     ///
@@ -219,8 +319,9 @@ impl TechniqueSolver {
     ///
     /// # Returns
     ///
-    /// * `Ok(true)` - A technique was applied and made progress
-    /// * `Ok(false)` - No technique could make progress (solver is stuck)
+    /// * `Ok(Some(step))` - A technique was applied and made progress, as
+    ///   described by `step`
+    /// * `Ok(None)` - No technique could make progress (solver is stuck)
     ///
     /// # Errors
     ///
@@ -237,8 +338,8 @@ impl TechniqueSolver {
     /// let mut grid = CandidateGrid::new();
     /// let mut stats = TechniqueSolverStats::new();
     ///
-    /// if solver.step(&mut grid, &mut stats)? {
-    ///     println!("Made progress!");
+    /// if let Some(step) = solver.step(&mut grid, &mut stats)? {
+    ///     println!("Made progress with {}!", step.technique());
     /// } else {
     ///     println!("Stuck - no technique can help");
     /// }
@@ -248,18 +349,68 @@ impl TechniqueSolver {
         &self,
         grid: &mut CandidateGrid,
         stats: &mut TechniqueSolverStats,
-    ) -> Result<bool, SolverError> {
+    ) -> Result<Option<Step>, SolverError> {
+        let step = self.apply_first(grid)?;
+        if let Some(step) = &step {
+            *stats.applications.entry(step.technique()).or_default() += 1;
+            stats.total_steps += 1;
+        }
+        Ok(step)
+    }
+
+    /// Returns the next step the solver would take, without modifying the grid.
+    ///
+    /// The techniques are tried in the same order as in [`step`](Self::step), on
+    /// a copy of `grid`, so the returned step is exactly the one `step` would
+    /// apply. This is the basis of a hint system: the step names the technique,
+    /// the cells and houses of its pattern, and the placements or eliminations
+    /// it leads to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(step))` - A technique can make progress, as described by `step`
+    /// * `Ok(None)` - No technique can make progress (solver is stuck)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Inconsistent`] if the grid is inconsistent, or
+    /// would become inconsistent after applying the step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_core::{CandidateGrid, Digit, Position};
+    /// use numelace_solver::TechniqueSolver;
+    ///
+    /// let solver = TechniqueSolver::with_all_techniques();
+    /// let mut grid = CandidateGrid::new();
+    /// grid.place(Position::new(0, 0), Digit::D5);
+    ///
+    /// let hint = solver.hint(&grid)?.unwrap();
+    /// assert_eq!(hint.technique(), "naked singles");
+    /// assert!(hint.cells().contains(Position::new(0, 0)));
+    /// // The grid itself is left untouched
+    /// assert!(grid.candidates_at(Position::new(1, 0)).contains(Digit::D5));
+    /// # Ok::<(), numelace_solver::SolverError>(())
+    /// ```
+    pub fn hint(&self, grid: &CandidateGrid) -> Result<Option<Step>, SolverError> {
+        self.apply_first(&mut grid.clone())
+    }
+
+    /// Applies the first technique that makes progress.
+    fn apply_first(&self, grid: &mut CandidateGrid) -> Result<Option<Step>, SolverError> {
         grid.check_consistency()?;
 
         for technique in &self.techniques {
-            if technique.apply(grid)? {
-                *stats.applications.entry(technique.name()).or_default() += 1;
-                stats.total_steps += 1;
+            if technique.requires_unique_solution() && !self.assume_unique_solution {
+                continue;
+            }
+            if let Some(step) = technique.apply(grid)? {
                 grid.check_consistency()?;
-                return Ok(true);
+                return Ok(Some(step));
             }
         }
-        Ok(false)
+        Ok(None)
     }
 
     /// Applies techniques repeatedly until the grid is solved or no progress can be made.
@@ -353,21 +504,147 @@ impl TechniqueSolver {
         grid: &mut CandidateGrid,
         stats: &mut TechniqueSolverStats,
     ) -> Result<bool, SolverError> {
-        while self.step(grid, stats)? {
+        self.solve_with_control(grid, stats, &SolveControl::new())
+    }
+
+    /// Applies techniques repeatedly until the grid is solved, no progress can be
+    /// made, or a limit of `control` is hit.
+    ///
+    /// This is [`solve_with_stats`](Self::solve_with_stats) bounded by `control`:
+    /// the cancel flag, the deadline and the step limit are checked before each
+    /// step, and each step taken is counted in `control`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the grid is completely solved, `false` if stuck.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Interrupted`] if a limit is hit or the run is
+    /// cancelled, leaving `grid` partially solved, and
+    /// [`SolverError::Inconsistent`] if the grid becomes inconsistent during
+    /// solving.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_core::{CandidateGrid, Digit, Position};
+    /// use numelace_solver::{
+    ///     Interruption, SolveControl, SolverError, TechniqueSolver, TechniqueSolverStats,
+    /// };
+    ///
+    /// let solver = TechniqueSolver::with_all_techniques();
+    /// let mut grid = CandidateGrid::new();
+    /// grid.place(Position::new(0, 0), Digit::D1);
+    /// grid.place(Position::new(4, 4), Digit::D2);
+    ///
+    /// let control = SolveControl::new().max_steps(1);
+    /// let result = solver.solve_with_control(&mut grid, &mut TechniqueSolverStats::new(), &control);
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(SolverError::Interrupted(Interruption::StepLimit))
+    /// ));
+    /// assert_eq!(control.steps(), 1);
+    /// ```
+    pub fn solve_with_control(
+        &self,
+        grid: &mut CandidateGrid,
+        stats: &mut TechniqueSolverStats,
+        control: &SolveControl,
+    ) -> Result<bool, SolverError> {
+        self.solve_with(grid, stats, control, |_| {})
+    }
+
+    /// Applies techniques repeatedly until the grid is solved or no progress can be made,
+    /// recording each step applied.
+    ///
+    /// The trace holds the technique and the placements and eliminations of each
+    /// step, in order. Replaying it onto a copy of the initial grid reproduces the
+    /// final grid, so it can be stored next to a puzzle, compared between solver
+    /// versions, or used to animate the solve.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple `(solved, trace)` where:
+    /// * `solved` - `true` if the grid is completely solved, `false` if stuck
+    /// * `trace` - The steps applied
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Inconsistent`] if the grid becomes inconsistent
+    /// during solving.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numelace_core::{CandidateGrid, Digit, Position};
+    /// use numelace_solver::TechniqueSolver;
+    ///
+    /// let solver = TechniqueSolver::with_all_techniques();
+    /// let mut grid = CandidateGrid::new();
+    /// grid.place(Position::new(0, 0), Digit::D5);
+    ///
+    /// let (solved, trace) = solver.solve_with_trace(&mut grid)?;
+    /// assert!(!solved);
+    /// for step in trace.steps() {
+    ///     println!("{step}");
+    /// }
+    /// # Ok::<(), numelace_solver::SolverError>(())
+    /// ```
+    pub fn solve_with_trace(
+        &self,
+        grid: &mut CandidateGrid,
+    ) -> Result<(bool, SolveTrace), SolverError> {
+        let mut trace = SolveTrace::new();
+        let solved = self.solve_with(
+            grid,
+            &mut TechniqueSolverStats::new(),
+            &SolveControl::new(),
+            |step| trace.push(TraceStep::from(step)),
+        )?;
+        Ok((solved, trace))
+    }
+
+    /// Applies techniques until the grid is solved, the solver is stuck or a
+    /// limit is hit, passing each step to `on_step`.
+    fn solve_with<F>(
+        &self,
+        grid: &mut CandidateGrid,
+        stats: &mut TechniqueSolverStats,
+        control: &SolveControl,
+        mut on_step: F,
+    ) -> Result<bool, SolverError>
+    where
+        F: FnMut(&Step),
+    {
+        loop {
+            control.check_step()?;
+            let Some(step) = self.step(grid, stats)? else {
+                return Ok(grid.is_solved()?);
+            };
+            control.record_step();
+            on_step(&step);
             if grid.is_solved()? {
                 return Ok(true);
             }
         }
-        Ok(grid.is_solved()?)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use numelace_core::{CandidateGrid, Digit, Position};
+    use std::str::FromStr as _;
+
+    use numelace_core::{CandidateGrid, Digit, DigitGrid, Position};
 
     use super::*;
-    use crate::technique::{BoxedTechnique, HiddenSingle, NakedSingle, all_techniques};
+    use crate::{
+        Candidate, Interruption,
+        technique::{
+            BoxedTechnique, HiddenSingle, NakedSingle, UniqueRectangle, UniqueRectangleKind,
+            all_techniques,
+        },
+    };
 
     fn create_test_solver() -> TechniqueSolver {
         let techniques: Vec<BoxedTechnique> =
@@ -376,7 +653,7 @@ mod tests {
     }
 
     #[test]
-    fn test_step_returns_false_when_no_progress() {
+    fn test_step_returns_none_when_no_progress() {
         let solver = create_test_solver();
         let mut grid = CandidateGrid::new();
         let mut stats = TechniqueSolverStats::new();
@@ -384,12 +661,12 @@ mod tests {
         // On a fresh grid with all candidates, no technique can make progress yet
         let result = solver.step(&mut grid, &mut stats);
         assert!(result.is_ok());
-        assert!(!result.unwrap());
+        assert!(result.unwrap().is_none());
         assert_eq!(stats.total_steps, 0);
     }
 
     #[test]
-    fn test_step_returns_true_when_progress_made() {
+    fn test_step_returns_step_when_progress_made() {
         let solver = create_test_solver();
         let mut grid = CandidateGrid::new();
         let mut stats = TechniqueSolverStats::new();
@@ -402,12 +679,103 @@ mod tests {
         }
 
         let result = solver.step(&mut grid, &mut stats);
-        assert!(result.is_ok());
-        assert!(result.unwrap());
+        let step = result.unwrap().unwrap();
+        assert_eq!(step.technique(), "naked singles");
+        // The cell is already decided, so its peers lose D5
+        assert_eq!(step.placements(), []);
+        assert_eq!(step.eliminations().len(), 20);
+        assert!(
+            step.eliminations()
+                .contains(&Candidate::new(Position::new(4, 0), Digit::D5))
+        );
+        assert!(step.cells().contains(Position::new(4, 4)));
         assert_eq!(stats.total_steps, 1);
         assert_eq!(stats.count("naked singles"), 1);
     }
 
+    #[test]
+    fn test_hint_matches_step_without_modifying_grid() {
+        let solver = TechniqueSolver::with_all_techniques();
+        let mut grid = CandidateGrid::new();
+        grid.place(Position::new(4, 4), Digit::D5);
+        grid.remove_candidate(Position::new(0, 0), Digit::D1);
+        let original = grid.clone();
+
+        let hint = solver.hint(&grid).unwrap().unwrap();
+        assert_eq!(grid, original);
+
+        let mut stats = TechniqueSolverStats::new();
+        let step = solver.step(&mut grid, &mut stats).unwrap().unwrap();
+        assert_eq!(hint, step);
+    }
+
+    #[test]
+    fn test_hint_returns_none_when_stuck() {
+        let solver = create_test_solver();
+        let grid = CandidateGrid::new();
+        assert_eq!(solver.hint(&grid).unwrap(), None);
+    }
+
+    #[test]
+    fn test_solve_with_control_stops_at_step_limit() {
+        let solver = create_test_solver();
+        // Generated with naked and hidden singles only
+        let problem = DigitGrid::from_str(
+            "...36..4..9.....2...67..1..5....987..31..............4..8...65.67.....3......3..2",
+        )
+        .unwrap();
+        let grid = CandidateGrid::from_digit_grid(&problem);
+
+        let (is_solved, unlimited_stats) = solver.solve(&mut grid.clone()).unwrap();
+        assert!(is_solved);
+        assert!(unlimited_stats.total_steps() > 1);
+
+        let control = SolveControl::new().max_steps(1);
+        let mut stats = TechniqueSolverStats::new();
+        let result = solver.solve_with_control(&mut grid.clone(), &mut stats, &control);
+        assert!(matches!(
+            result,
+            Err(SolverError::Interrupted(Interruption::StepLimit))
+        ));
+        assert_eq!(stats.total_steps(), 1);
+        assert_eq!(control.steps(), 1);
+    }
+
+    #[test]
+    fn test_solve_with_trace_replays_to_same_grid() {
+        let solver = create_test_solver();
+        let problem = DigitGrid::from_str(
+            "...36..4..9.....2...67..1..5....987..31..............4..8...65.67.....3......3..2",
+        )
+        .unwrap();
+        let start = CandidateGrid::from_digit_grid(&problem);
+
+        let mut grid = start.clone();
+        let (is_solved, trace) = solver.solve_with_trace(&mut grid).unwrap();
+        assert!(is_solved);
+        let (_, stats) = solver.solve(&mut start.clone()).unwrap();
+        assert_eq!(trace.len(), stats.total_steps());
+
+        let parsed: SolveTrace = trace.to_string().parse().unwrap();
+        let mut replayed = start;
+        parsed.replay(&mut replayed).unwrap();
+        assert_eq!(replayed, grid);
+    }
+
+    #[test]
+    fn test_solve_with_control_stops_when_cancelled() {
+        let solver = create_test_solver();
+        let control = SolveControl::new();
+        control.cancel();
+        let mut stats = TechniqueSolverStats::new();
+        let result = solver.solve_with_control(&mut CandidateGrid::new(), &mut stats, &control);
+        assert!(matches!(
+            result,
+            Err(SolverError::Interrupted(Interruption::Cancelled))
+        ));
+        assert!(!stats.has_progress());
+    }
+
     #[test]
     fn test_step_records_stats() {
         let solver = create_test_solver();
@@ -429,6 +797,43 @@ mod tests {
         assert_eq!(stats.count("hidden singles"), 0);
     }
 
+    #[test]
+    fn test_step_skips_uniqueness_techniques_unless_enabled() {
+        let techniques: Vec<BoxedTechnique> =
+            vec![Box::new(UniqueRectangle::new(UniqueRectangleKind::Type1))];
+        let solver = TechniqueSolver::new(techniques);
+
+        // Unique rectangle type 1 on (0, 0), (4, 0), (0, 1) and (4, 1)
+        let mut grid = CandidateGrid::new();
+        for pos in [
+            Position::new(0, 0),
+            Position::new(4, 0),
+            Position::new(0, 1),
+        ] {
+            for digit in Digit::ALL {
+                if digit != Digit::D1 && digit != Digit::D2 {
+                    grid.remove_candidate(pos, digit);
+                }
+            }
+        }
+
+        let mut stats = TechniqueSolverStats::new();
+        assert!(
+            solver
+                .step(&mut grid.clone(), &mut stats)
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(stats.total_steps, 0);
+
+        let solver = solver.assume_unique_solution(true);
+        let step = solver.step(&mut grid, &mut stats).unwrap().unwrap();
+        assert_eq!(step.technique(), "unique rectangle type 1");
+        assert_eq!(step.cells().len(), 4);
+        assert_eq!(stats.count("unique rectangle type 1"), 1);
+        assert!(!grid.candidates_at(Position::new(4, 1)).contains(Digit::D1));
+    }
+
     #[test]
     fn test_solve_empty_grid() {
         let solver = create_test_solver();
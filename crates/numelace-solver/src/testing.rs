@@ -13,7 +13,7 @@
 //! # impl Technique for DummyTechnique {
 //! #     fn name(&self) -> &str { "dummy" }
 //! #     fn clone_box(&self) -> Box<dyn Technique> { Box::new(DummyTechnique) }
-//! #     fn apply(&self, _: &mut numelace_core::CandidateGrid) -> Result<bool, numelace_solver::SolverError> { Ok(false) }
+//! #     fn apply(&self, _: &mut numelace_core::CandidateGrid) -> Result<Option<numelace_solver::Step>, numelace_solver::SolverError> { Ok(None) }
 //! # }
 //! # let technique = DummyTechnique;
 //! TechniqueTester::from_str("
@@ -123,7 +123,7 @@ impl TechniqueTester {
     where
         T: Technique,
     {
-        while technique.apply(&mut self.current).unwrap() {}
+        while technique.apply(&mut self.current).unwrap().is_some() {}
         self
     }
 
@@ -251,11 +251,20 @@ impl TechniqueTester {
     }
 }
 
+/// Removes every candidate at `pos` except `digits`.
+pub fn restrict(grid: &mut CandidateGrid, pos: Position, digits: &[Digit]) {
+    for digit in Digit::ALL {
+        if !digits.contains(&digit) {
+            grid.remove_candidate(pos, digit);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    // Mock technique for testing that always returns false (no change)
+    // Mock technique for testing that always returns `None` (no change)
     #[derive(Debug)]
     struct NoOpTechnique;
 
@@ -268,8 +277,11 @@ mod tests {
             Box::new(NoOpTechnique)
         }
 
-        fn apply(&self, _grid: &mut CandidateGrid) -> Result<bool, crate::SolverError> {
-            Ok(false)
+        fn apply(
+            &self,
+            _grid: &mut CandidateGrid,
+        ) -> Result<Option<crate::Step>, crate::SolverError> {
+            Ok(None)
         }
     }
 
@@ -286,14 +298,24 @@ mod tests {
             Box::new(PlaceD1At00)
         }
 
-        fn apply(&self, grid: &mut CandidateGrid) -> Result<bool, crate::SolverError> {
+        fn apply(
+            &self,
+            grid: &mut CandidateGrid,
+        ) -> Result<Option<crate::Step>, crate::SolverError> {
             let pos = Position::new(0, 0);
             let candidates = grid.candidates_at(pos);
             if candidates.len() == 1 {
-                Ok(false)
+                Ok(None)
             } else {
+                let before = grid.clone();
                 grid.place(pos, Digit::D1);
-                Ok(true)
+                let placements = vec![crate::Candidate::new(pos, Digit::D1)];
+                Ok(crate::Step::from_placements(
+                    self.name(),
+                    &before,
+                    grid,
+                    placements,
+                ))
             }
         }
     }
@@ -355,7 +377,7 @@ mod tests {
         ",
         );
 
-        // PlaceD1At00 will apply once, then return false
+        // PlaceD1At00 will apply once, then return `None`
         let result = tester.apply_until_stuck(&PlaceD1At00);
         let _ = result;
     }
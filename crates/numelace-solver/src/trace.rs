@@ -0,0 +1,391 @@
+//! Recorded solve paths.
+//!
+//! A [`SolveTrace`] is the ordered list of steps a solver applied, reduced to
+//! what is needed to reproduce them: the technique name and the grid changes.
+//! It can be replayed onto a [`CandidateGrid`] and converted to and from a
+//! line-based text format, one step per line:
+//!
+//! ```text
+//! naked singles: r1c2<>5 r1c3<>5
+//! hidden singles: r4c4=7
+//! ```
+//!
+//! Cells are written with 1-based row and column numbers. `r4c4=7` places 7 in
+//! row 4, column 4, and `r1c2<>5` eliminates candidate 5 from row 1, column 2.
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use numelace_core::{CandidateGrid, Digit, Position};
+
+use crate::{Candidate, Step};
+
+/// One step of a [`SolveTrace`]: the technique applied and the changes it made.
+///
+/// Unlike [`Step`], a trace step owns its technique name, so that it can be
+/// parsed from text, and leaves out the pattern that justified the changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStep {
+    technique: String,
+    placements: Vec<Candidate>,
+    eliminations: Vec<Candidate>,
+}
+
+impl TraceStep {
+    /// Creates a trace step of `technique` with the given changes.
+    #[must_use]
+    pub fn new(
+        technique: impl Into<String>,
+        placements: Vec<Candidate>,
+        eliminations: Vec<Candidate>,
+    ) -> Self {
+        Self {
+            technique: technique.into(),
+            placements,
+            eliminations,
+        }
+    }
+
+    /// Returns the name of the technique that was applied.
+    #[must_use]
+    pub fn technique(&self) -> &str {
+        &self.technique
+    }
+
+    /// Returns the digits placed by the step.
+    #[must_use]
+    pub fn placements(&self) -> &[Candidate] {
+        &self.placements
+    }
+
+    /// Returns the candidates eliminated by the step.
+    #[must_use]
+    pub fn eliminations(&self) -> &[Candidate] {
+        &self.eliminations
+    }
+
+    /// Applies the changes of the step to `grid`.
+    ///
+    /// Every placed digit and eliminated candidate must still be a candidate
+    /// of `grid`. Changes before the first missing candidate are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first change whose candidate is missing from `grid`.
+    pub fn apply(&self, grid: &mut CandidateGrid) -> Result<(), Candidate> {
+        for &candidate in &self.placements {
+            if !is_candidate(grid, candidate) {
+                return Err(candidate);
+            }
+            grid.place(candidate.pos(), candidate.digit());
+        }
+        for &candidate in &self.eliminations {
+            if !grid.remove_candidate(candidate.pos(), candidate.digit()) {
+                return Err(candidate);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<&Step> for TraceStep {
+    fn from(step: &Step) -> Self {
+        Self::new(
+            step.technique(),
+            step.placements().to_vec(),
+            step.eliminations().to_vec(),
+        )
+    }
+}
+
+impl Display for TraceStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.technique)?;
+        for candidate in &self.placements {
+            write!(f, " {}={}", candidate.pos(), candidate.digit())?;
+        }
+        for candidate in &self.eliminations {
+            write!(f, " {}<>{}", candidate.pos(), candidate.digit())?;
+        }
+        Ok(())
+    }
+}
+
+/// The ordered steps applied while solving a grid.
+///
+/// Created by [`TechniqueSolver::solve_with_trace`](crate::TechniqueSolver::solve_with_trace).
+///
+/// # Examples
+///
+/// ```
+/// use numelace_core::{CandidateGrid, Digit, Position};
+/// use numelace_solver::{SolveTrace, TechniqueSolver};
+///
+/// let solver = TechniqueSolver::with_all_techniques();
+/// let mut grid = CandidateGrid::new();
+/// grid.place(Position::new(0, 0), Digit::D5);
+/// let start = grid.clone();
+///
+/// let (_, trace) = solver.solve_with_trace(&mut grid)?;
+///
+/// // The text form can be stored and parsed back
+/// let text = trace.to_string();
+/// assert!(text.starts_with("naked singles: r1c2<>5"));
+/// let parsed: SolveTrace = text.parse()?;
+/// assert_eq!(parsed, trace);
+///
+/// // Replaying the trace reproduces the solver's changes
+/// let mut replayed = start;
+/// parsed.replay(&mut replayed)?;
+/// assert_eq!(replayed, grid);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SolveTrace {
+    steps: Vec<TraceStep>,
+}
+
+impl SolveTrace {
+    /// Creates an empty trace.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a step to the trace.
+    pub fn push(&mut self, step: TraceStep) {
+        self.steps.push(step);
+    }
+
+    /// Returns the steps of the trace, in the order they were applied.
+    #[must_use]
+    pub fn steps(&self) -> &[TraceStep] {
+        &self.steps
+    }
+
+    /// Returns the number of steps in the trace.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns `true` if the trace has no steps.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Applies every step of the trace to `grid`, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TraceReplayError`] if a step places or eliminates a digit that
+    /// is not a candidate of the grid at that point. The steps before it are
+    /// kept.
+    pub fn replay(&self, grid: &mut CandidateGrid) -> Result<(), TraceReplayError> {
+        for (step, trace_step) in self.steps.iter().enumerate() {
+            trace_step
+                .apply(grid)
+                .map_err(|candidate| TraceReplayError { step, candidate })?;
+        }
+        Ok(())
+    }
+}
+
+impl FromIterator<TraceStep> for SolveTrace {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = TraceStep>,
+    {
+        Self {
+            steps: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Display for SolveTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for step in &self.steps {
+            writeln!(f, "{step}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for SolveTrace {
+    type Err = TraceParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut trace = Self::new();
+        for (line_number, line) in (1..).zip(s.lines()) {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            // Changes never contain a colon, technique names might
+            let Some((technique, changes)) = line.rsplit_once(':') else {
+                return Err(TraceParseError::MissingTechnique { line: line_number });
+            };
+            let mut step = TraceStep::new(technique.trim(), vec![], vec![]);
+            for change in changes.split_whitespace() {
+                match parse_change(change) {
+                    Some(Change::Place(candidate)) => step.placements.push(candidate),
+                    Some(Change::Eliminate(candidate)) => step.eliminations.push(candidate),
+                    None => {
+                        return Err(TraceParseError::InvalidChange {
+                            line: line_number,
+                            change: change.to_owned(),
+                        });
+                    }
+                }
+            }
+            trace.push(step);
+        }
+        Ok(trace)
+    }
+}
+
+/// Error returned when a [`SolveTrace`] does not apply to a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+#[display(
+    "step {} does not apply: {} is not a candidate of {}",
+    step + 1,
+    candidate.digit(),
+    candidate.pos()
+)]
+pub struct TraceReplayError {
+    step: usize,
+    candidate: Candidate,
+}
+
+impl TraceReplayError {
+    /// Returns the index of the step that does not apply.
+    #[must_use]
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Returns the placed or eliminated candidate missing from the grid.
+    #[must_use]
+    pub fn candidate(&self) -> Candidate {
+        self.candidate
+    }
+}
+
+/// Errors that can occur when parsing a [`SolveTrace`] from text.
+///
+/// Lines are numbered from 1.
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+pub enum TraceParseError {
+    /// A line has no `technique:` prefix.
+    #[display("line {line}: missing technique name")]
+    MissingTechnique {
+        /// The line number.
+        line: usize,
+    },
+    /// A change is neither `rXcY=D` nor `rXcY<>D`.
+    #[display("line {line}: invalid change '{change}'")]
+    InvalidChange {
+        /// The line number.
+        line: usize,
+        /// The invalid change.
+        change: String,
+    },
+}
+
+enum Change {
+    Place(Candidate),
+    Eliminate(Candidate),
+}
+
+/// Parses `rXcY=D` or `rXcY<>D`.
+fn parse_change(s: &str) -> Option<Change> {
+    let (change, cell, digit): (fn(Candidate) -> Change, _, _) =
+        if let Some((cell, digit)) = s.split_once("<>") {
+            (Change::Eliminate, cell, digit)
+        } else {
+            let (cell, digit) = s.split_once('=')?;
+            (Change::Place, cell, digit)
+        };
+    let (y, x) = cell.strip_prefix('r')?.split_once('c')?;
+    let pos = Position::try_new(parse_number(x)? - 1, parse_number(y)? - 1).ok()?;
+    let digit = Digit::from_value(parse_number(digit)?);
+    Some(change(Candidate::new(pos, digit)))
+}
+
+/// Parses a number from 1 to 9.
+fn parse_number(s: &str) -> Option<u8> {
+    s.parse().ok().filter(|n| (1..=9).contains(n))
+}
+
+fn is_candidate(grid: &CandidateGrid, candidate: Candidate) -> bool {
+    grid.candidates_at(candidate.pos())
+        .contains(candidate.digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(x: u8, y: u8, digit: Digit) -> Candidate {
+        Candidate::new(Position::new(x, y), digit)
+    }
+
+    #[test]
+    fn test_text_roundtrip() {
+        let trace: SolveTrace = [
+            TraceStep::new(
+                "naked singles",
+                vec![],
+                vec![candidate(1, 0, Digit::D5), candidate(8, 8, Digit::D9)],
+            ),
+            TraceStep::new("hidden singles", vec![candidate(3, 3, Digit::D7)], vec![]),
+        ]
+        .into_iter()
+        .collect();
+
+        let text = trace.to_string();
+        assert_eq!(
+            text,
+            "naked singles: r1c2<>5 r9c9<>9\nhidden singles: r4c4=7\n"
+        );
+        assert_eq!(text.parse::<SolveTrace>().unwrap(), trace);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "\nnaked singles r1c1=1".parse::<SolveTrace>(),
+            Err(TraceParseError::MissingTechnique { line: 2 })
+        );
+        for change in ["r0c1=1", "r1c10=1", "r1c1=0", "r1c1#1", "c1r1=1"] {
+            assert_eq!(
+                format!("x: {change}").parse::<SolveTrace>(),
+                Err(TraceParseError::InvalidChange {
+                    line: 1,
+                    change: change.to_owned()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_replay_rejects_missing_candidates() {
+        let trace: SolveTrace = "a: r1c1=1\nb: r1c2<>1\nc: r1c2<>1".parse().unwrap();
+        let mut grid = CandidateGrid::new();
+        let err = trace.replay(&mut grid).unwrap_err();
+        assert_eq!(err.step(), 2);
+        assert_eq!(err.candidate(), candidate(1, 0, Digit::D1));
+        assert_eq!(
+            err.to_string(),
+            "step 3 does not apply: 1 is not a candidate of r1c2"
+        );
+
+        // A placement needs the digit to be a candidate
+        let mut grid = CandidateGrid::new();
+        grid.remove_candidate(Position::new(0, 0), Digit::D1);
+        assert!(trace.replay(&mut grid).is_err());
+    }
+}